@@ -1,7 +1,14 @@
 //! 数据传输对象（DTO）
 
 use serde::{Deserialize, Serialize};
-use crate::domain::Beacon;
+use crate::api::query::{Page, PageInfo, MAX_LIMIT};
+use crate::config::AppConfig;
+use crate::domain::{Beacon, BeaconId, BeaconTemplate, Location, LocationVersion};
+use crate::error::{AppError, Result};
+use crate::infrastructure::jobs::JobStatus;
+use crate::domain::coverage::{
+    CoverageGrid, CoverageMap, CoverageSimulation, PathLossModel, PlannedBeacon, MAX_GRID_CELLS,
+};
 
 /// API 响应体
 #[derive(Debug, Serialize)]
@@ -15,6 +22,9 @@ pub struct ApiResponse<T: Serialize> {
     /// 响应数据
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<T>,
+    /// 分页信息，仅列表接口返回
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<PageInfo>,
 }
 
 impl<T: Serialize> ApiResponse<T> {
@@ -25,6 +35,17 @@ impl<T: Serialize> ApiResponse<T> {
             if_success: true,
             message,
             data: Some(data),
+            page: None,
+        }
+    }
+}
+
+impl<T: Serialize> ApiResponse<Vec<T>> {
+    /// 创建列表接口的成功响应，`data` 为当前页数据
+    pub fn paged(message: String, page: Page<T>) -> Self {
+        Self {
+            page: Some(page.info),
+            ..Self::success(message, page.items)
         }
     }
 }
@@ -52,7 +73,7 @@ impl ErrorResponse {
 /// Beacon 响应 DTO
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BeaconDto {
-    pub id: String,
+    pub id: BeaconId,
     pub uuid: String,
     pub major: i32,
     pub minor: i32,
@@ -60,6 +81,8 @@ pub struct BeaconDto {
     pub power: i32,
     pub interval: i32,
     pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixture_id: Option<String>,
 }
 
 /// Location 响应 DTO
@@ -80,16 +103,11 @@ impl From<&Beacon> for BeaconDto {
             uuid: beacon.uuid.clone(),
             major: beacon.major,
             minor: beacon.minor,
-            location: LocationDto {
-                x: beacon.location.x,
-                y: beacon.location.y,
-                z: beacon.location.z,
-                floor: beacon.location.floor.clone(),
-                area_id: beacon.location.area_id.clone(),
-            },
+            location: LocationDto::from(&beacon.location),
             power: beacon.power,
             interval: beacon.interval,
             status: beacon.status.clone(),
+            fixture_id: beacon.fixture_id.clone(),
         }
     }
 }
@@ -99,3 +117,386 @@ impl From<Beacon> for BeaconDto {
         Self::from(&beacon)
     }
 }
+
+impl From<&Location> for LocationDto {
+    fn from(location: &Location) -> Self {
+        Self {
+            x: location.x,
+            y: location.y,
+            z: location.z,
+            floor: location.floor.clone(),
+            area_id: location.area_id.clone(),
+        }
+    }
+}
+
+impl From<LocationDto> for Location {
+    fn from(dto: LocationDto) -> Self {
+        Location::new(dto.x, dto.y, dto.z, dto.floor, dto.area_id)
+    }
+}
+
+impl TryFrom<BeaconDto> for Beacon {
+    type Error = AppError;
+
+    /// 转换并校验客户端提交的Beacon
+    fn try_from(dto: BeaconDto) -> Result<Self> {
+        let mut beacon = Beacon::new(
+            dto.id,
+            dto.uuid,
+            dto.major,
+            dto.minor,
+            dto.location.into(),
+            dto.power,
+            dto.interval,
+            dto.status,
+        );
+        beacon.fixture_id = dto.fixture_id;
+        beacon.validate()?;
+        Ok(beacon)
+    }
+}
+
+/// 覆盖仿真请求 DTO
+#[derive(Debug, Deserialize)]
+pub struct CoverageRequest {
+    /// 楼层标识，仅用于回显
+    #[serde(default)]
+    pub floor: Option<String>,
+    /// 规划中的信标位置
+    pub beacons: Vec<PlannedBeacon>,
+    /// 仿真网格
+    pub grid: CoverageGrid,
+    /// 路径损耗指数，缺省为自由空间值 2.0
+    #[serde(default)]
+    pub path_loss_exponent: Option<f64>,
+    /// 可接收的最低信号强度（dBm）
+    #[serde(default = "default_rssi_threshold")]
+    pub rssi_threshold: f64,
+    /// 可定位所需的最少信标数
+    #[serde(default = "default_min_beacons")]
+    pub min_beacons: usize,
+}
+
+fn default_rssi_threshold() -> f64 {
+    -90.0
+}
+
+fn default_min_beacons() -> usize {
+    3
+}
+
+impl From<CoverageRequest> for CoverageSimulation {
+    fn from(request: CoverageRequest) -> Self {
+        let model = match request.path_loss_exponent {
+            Some(exponent) => PathLossModel { exponent },
+            None => PathLossModel::default(),
+        };
+
+        Self {
+            beacons: request.beacons,
+            grid: request.grid,
+            model,
+            rssi_threshold: request.rssi_threshold,
+            min_beacons: request.min_beacons,
+        }
+    }
+}
+
+/// 覆盖仿真结果 DTO
+#[derive(Debug, Serialize)]
+pub struct CoverageDto {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub floor: Option<String>,
+    /// 网格左下角（第0行第0列单元的角点）
+    pub origin_x: f64,
+    pub origin_y: f64,
+    pub cell_size: f64,
+    pub rows: usize,
+    pub columns: usize,
+    pub signal_counts: Vec<Vec<usize>>,
+    pub hdop: Vec<Vec<Option<f64>>>,
+    pub covered_ratio: f64,
+    pub mean_hdop: Option<f64>,
+}
+
+impl CoverageDto {
+    pub fn new(floor: Option<String>, grid: &CoverageGrid, map: CoverageMap) -> Self {
+        Self {
+            floor,
+            origin_x: grid.min_x,
+            origin_y: grid.min_y,
+            cell_size: grid.cell_size,
+            rows: map.rows,
+            columns: map.columns,
+            signal_counts: map.signal_counts,
+            hdop: map.hdop,
+            covered_ratio: map.covered_ratio,
+            mean_hdop: map.mean_hdop,
+        }
+    }
+}
+
+/// 变更日志重放参数
+#[derive(Debug, Deserialize)]
+pub struct ReplayQuery {
+    /// 恢复到该序号时的状态，缺省时重放全部日志
+    pub up_to_seq: Option<u64>,
+}
+
+/// 覆盖仿真执行方式
+#[derive(Debug, Default, Deserialize)]
+pub struct CoverageQuery {
+    /// 为真时作为后台任务执行，立即返回任务信息
+    #[serde(default, rename = "async")]
+    pub run_async: bool,
+}
+
+/// 任务列表过滤条件
+#[derive(Debug, Deserialize)]
+pub struct JobFilter {
+    pub status: Option<JobStatus>,
+}
+
+/// 服务能力描述，供客户端SDK按服务端构建与配置调整行为
+#[derive(Debug, Serialize)]
+pub struct CapabilitiesDto {
+    /// 服务端版本
+    pub version: &'static str,
+    /// 支持的API版本
+    pub api_versions: Vec<&'static str>,
+    /// 支持的定位算法
+    pub positioning_algorithms: Vec<&'static str>,
+    /// 覆盖仿真可用的信号传播模型
+    pub planning_models: Vec<&'static str>,
+    /// HTTP协议版本
+    pub http_versions: Vec<&'static str>,
+    /// 响应压缩编码
+    pub response_encodings: Vec<&'static str>,
+    /// 可解压的请求体编码
+    pub request_encodings: Vec<&'static str>,
+    pub subsystems: SubsystemsDto,
+    pub limits: LimitsDto,
+}
+
+/// 各子系统是否可用
+#[derive(Debug, Serialize)]
+pub struct SubsystemsDto {
+    pub mqtt: bool,
+    pub grpc: bool,
+    pub websocket: bool,
+    /// 后台任务
+    pub jobs: bool,
+    /// 变更日志是否持久化
+    pub persistent_journal: bool,
+    /// 被拒绝请求记录
+    pub rejection_log: bool,
+    /// 信标标签签发
+    pub labels: bool,
+}
+
+/// 请求限制
+#[derive(Debug, Serialize)]
+pub struct LimitsDto {
+    /// 列表接口每页最大条数
+    pub max_page_size: usize,
+    /// 覆盖仿真最大网格单元数
+    pub max_coverage_cells: usize,
+}
+
+impl CapabilitiesDto {
+    pub fn from_config(config: &AppConfig) -> Self {
+        let encodings = |enabled: bool| if enabled { vec!["gzip", "br"] } else { Vec::new() };
+        let mut http_versions = vec!["http/1.1"];
+        if config.server.http2_enabled {
+            http_versions.push("h2c");
+        }
+
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            api_versions: vec!["1"],
+            positioning_algorithms: Vec::new(),
+            planning_models: vec!["log_distance_path_loss"],
+            http_versions,
+            response_encodings: encodings(config.compression.enabled),
+            request_encodings: encodings(config.compression.decompress_requests),
+            subsystems: SubsystemsDto {
+                mqtt: false,
+                grpc: false,
+                websocket: false,
+                jobs: true,
+                persistent_journal: config.storage.data_dir.is_some(),
+                rejection_log: config.rejections.enabled,
+                labels: config.label.signing_key.is_some(),
+            },
+            limits: LimitsDto {
+                max_page_size: MAX_LIMIT,
+                max_coverage_cells: MAX_GRID_CELLS,
+            },
+        }
+    }
+}
+
+/// 未指定状态时的默认值
+const DEFAULT_STATUS: &str = "active";
+
+/// 创建Beacon请求
+///
+/// 合并规则：请求中填写的字段优先，未填写的字段取自模板，
+/// 模板中也未设置时使用内置默认值（仅状态有默认值 `active`），
+/// 其余必填字段仍缺失时创建失败
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateBeaconRequest {
+    /// 配置模板ID，未填写的字段取自该模板
+    pub template_id: Option<String>,
+    pub id: BeaconId,
+    pub uuid: Option<String>,
+    pub major: i32,
+    pub minor: i32,
+    pub location: LocationDto,
+    pub power: Option<i32>,
+    pub interval: Option<i32>,
+    pub status: Option<String>,
+    pub fixture_id: Option<String>,
+}
+
+impl CreateBeaconRequest {
+    /// 按合并规则与模板合并，得到完整的Beacon DTO
+    ///
+    /// 只检查必填字段，取值由 `Beacon::try_from` 校验
+    pub fn into_dto(self, template: Option<&BeaconTemplate>) -> Result<BeaconDto> {
+        let template = template.cloned().unwrap_or_default();
+        let missing = |field: &str| {
+            AppError::ValidationError(format!("{} is required when not set by the template", field))
+        };
+
+        Ok(BeaconDto {
+            id: self.id,
+            uuid: self.uuid.or(template.uuid).ok_or_else(|| missing("uuid"))?,
+            major: self.major,
+            minor: self.minor,
+            location: self.location,
+            power: self.power.or(template.power).ok_or_else(|| missing("power"))?,
+            interval: self.interval.or(template.interval).ok_or_else(|| missing("interval"))?,
+            status: self
+                .status
+                .or(template.status)
+                .unwrap_or_else(|| DEFAULT_STATUS.to_string()),
+            fixture_id: self.fixture_id,
+        })
+    }
+}
+
+/// Beacon位置历史过滤条件
+#[derive(Debug, Deserialize)]
+pub struct LocationHistoryFilter {
+    /// 只返回该时刻（Unix毫秒）有效的版本
+    pub at: Option<u64>,
+}
+
+/// 标签校验参数
+#[derive(Debug, Deserialize)]
+pub struct LabelVerifyQuery {
+    /// 二维码中的标签令牌
+    pub token: String,
+}
+
+/// Beacon位置版本 DTO
+#[derive(Debug, Serialize)]
+pub struct LocationVersionDto {
+    pub location: LocationDto,
+    pub valid_from_ms: u64,
+    pub valid_to_ms: Option<u64>,
+}
+
+impl From<&LocationVersion> for LocationVersionDto {
+    fn from(version: &LocationVersion) -> Self {
+        Self {
+            location: LocationDto::from(&version.location),
+            valid_from_ms: version.valid_from_ms,
+            valid_to_ms: version.valid_to_ms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::BeaconBuilder;
+
+    #[test]
+    fn test_beacon_dto_round_trip() {
+        let beacon = BeaconBuilder::new("beacon_100")
+            .minor(7)
+            .at(1.5, 2.5, 3.0)
+            .floor("2F")
+            .area("area_003")
+            .build();
+
+        let dto = BeaconDto::from(&beacon);
+        assert_eq!(dto.location.floor, "2F");
+        assert_eq!(Beacon::try_from(dto).unwrap(), beacon);
+    }
+
+    #[test]
+    fn test_invalid_beacon_dto_rejected() {
+        let mut dto = BeaconDto::from(BeaconBuilder::new("beacon_100").build());
+        dto.location.area_id.clear();
+        assert!(Beacon::try_from(dto).is_err());
+    }
+
+    fn request() -> CreateBeaconRequest {
+        serde_json::from_value(serde_json::json!({
+            "id": "beacon_100",
+            "major": 1,
+            "minor": 2,
+            "location": {"x": 1.0, "y": 2.0, "z": 3.0, "floor": "1F", "area_id": "area_001"},
+        }))
+        .unwrap()
+    }
+
+    fn template() -> BeaconTemplate {
+        BeaconTemplate {
+            id: "warehouse".to_string(),
+            uuid: Some("FDA50693-A4E2-4FB1-AFCF-C6EB07647825".to_string()),
+            power: Some(-65),
+            interval: Some(500),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_template_fills_missing_fields() {
+        let dto = request().into_dto(Some(&template())).unwrap();
+        assert_eq!(dto.uuid, "FDA50693-A4E2-4FB1-AFCF-C6EB07647825");
+        assert_eq!(dto.power, -65);
+        assert_eq!(dto.interval, 500);
+        assert_eq!(dto.status, "active");
+        assert!(Beacon::try_from(dto).is_ok());
+    }
+
+    #[test]
+    fn test_request_overrides_template() {
+        let mut request = request();
+        request.power = Some(-80);
+        request.status = Some("inactive".to_string());
+
+        let dto = request.into_dto(Some(&template())).unwrap();
+        assert_eq!(dto.power, -80);
+        assert_eq!(dto.interval, 500);
+        assert_eq!(dto.status, "inactive");
+    }
+
+    #[test]
+    fn test_missing_required_fields() {
+        assert!(matches!(request().into_dto(None), Err(AppError::ValidationError(_))));
+
+        let partial = BeaconTemplate { power: None, ..template() };
+        assert!(request().into_dto(Some(&partial)).is_err());
+
+        let mut complete = request();
+        complete.power = Some(5);
+        let dto = complete.into_dto(Some(&partial)).unwrap();
+        assert!(Beacon::try_from(dto).is_err());
+    }
+}
@@ -0,0 +1,261 @@
+//! 管理处理程序
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+
+use crate::api::dto::{ApiResponse, ReplayQuery};
+use crate::api::query::{Filter, JsonBody, Pagination, PathParam, TimeRange};
+use crate::config::ConfigOverrides;
+use crate::domain::BeaconTemplate;
+use crate::error::Result;
+use crate::infrastructure::rejections::RejectionFilter;
+use crate::infrastructure::AppState;
+
+/// 获取当前生效的完整配置及每项配置的来源
+pub async fn get_effective_config(
+    State(state): State<Arc<AppState>>,
+) -> impl IntoResponse {
+    let response = ApiResponse::success(
+        "获取生效配置成功".to_string(),
+        state.admin_service().effective_config(),
+    );
+    (StatusCode::OK, Json(response))
+}
+
+/// 获取已保存的配置覆盖，敏感值已脱敏
+pub async fn get_config_overrides(
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse> {
+    let overrides = state.admin_service().config_overrides().await?;
+    let response = ApiResponse::success("获取配置覆盖成功".to_string(), overrides);
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// 整体替换配置覆盖，重启后生效
+///
+/// 需要配置持久化目录，`storage.data_dir` 不可覆盖
+pub async fn put_config_overrides(
+    State(state): State<Arc<AppState>>,
+    JsonBody(overrides): JsonBody<ConfigOverrides>,
+) -> Result<impl IntoResponse> {
+    let overrides = state.admin_service().save_config_overrides(overrides).await?;
+    let response = ApiResponse::success("保存配置覆盖成功，重启后生效".to_string(), overrides);
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// 获取配置变更日志
+pub async fn get_journal(
+    State(state): State<Arc<AppState>>,
+    pagination: Pagination,
+    range: TimeRange,
+) -> Result<impl IntoResponse> {
+    let mut entries = state.admin_service().journal().await?;
+    entries.retain(|entry| range.contains(entry.at_ms));
+
+    let response = ApiResponse::paged("获取变更日志成功".to_string(), pagination.paginate(entries));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// 重放变更日志以重建Beacon数据
+pub async fn replay_journal(
+    State(state): State<Arc<AppState>>,
+    Filter(query): Filter<ReplayQuery>,
+) -> Result<impl IntoResponse> {
+    let summary = state.admin_service().replay(query.up_to_seq).await?;
+
+    let response = ApiResponse::success("重放变更日志成功".to_string(), summary);
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// 查询被拒绝的请求记录
+pub async fn get_rejections(
+    State(state): State<Arc<AppState>>,
+    pagination: Pagination,
+    range: TimeRange,
+    Filter(filter): Filter<RejectionFilter>,
+) -> impl IntoResponse {
+    let mut entries = state.admin_service().rejections(&filter);
+    entries.retain(|entry| range.contains(entry.at_ms));
+
+    let response = ApiResponse::paged("获取被拒绝请求记录成功".to_string(), pagination.paginate(entries));
+    (StatusCode::OK, Json(response))
+}
+
+/// 获取所有Beacon配置模板
+pub async fn list_templates(
+    State(state): State<Arc<AppState>>,
+    pagination: Pagination,
+) -> Result<impl IntoResponse> {
+    let templates = state.template_service().list().await?;
+    let response = ApiResponse::paged("获取配置模板成功".to_string(), pagination.paginate(templates));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// 获取Beacon配置模板
+pub async fn get_template(
+    State(state): State<Arc<AppState>>,
+    PathParam(id): PathParam<String>,
+) -> Result<impl IntoResponse> {
+    let template = state.template_service().get(&id).await?;
+    let response = ApiResponse::success("获取配置模板成功".to_string(), template);
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// 保存Beacon配置模板，同ID模板已存在时整体替换
+///
+/// 模板ID取自路径，请求体中的 `id` 被忽略
+pub async fn put_template(
+    State(state): State<Arc<AppState>>,
+    PathParam(id): PathParam<String>,
+    JsonBody(template): JsonBody<BeaconTemplate>,
+) -> Result<impl IntoResponse> {
+    let template = state.template_service().save(id, template).await?;
+    let response = ApiResponse::success("保存配置模板成功".to_string(), template);
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// 删除Beacon配置模板，已用该模板创建的Beacon不受影响
+pub async fn delete_template(
+    State(state): State<Arc<AppState>>,
+    PathParam(id): PathParam<String>,
+) -> Result<impl IntoResponse> {
+    state.template_service().delete(&id).await?;
+    let response = ApiResponse::success("删除配置模板成功".to_string(), id);
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::AppConfig;
+    use crate::config::ServerConfig;
+    use crate::infrastructure::AppState;
+    use crate::testkit::{BeaconBuilder, TestServer};
+    use hyper::{Method, StatusCode};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_get_effective_config() {
+        let file = json!({ "log_level": "debug" });
+        let config = AppConfig::load_from(Some(&file), Vec::new()).unwrap();
+        let server = TestServer::spawn_with(AppState::with_config(config), ServerConfig::default()).await;
+
+        let (status, body) = server.get("/api/admin/config/effective").await;
+        assert_eq!(status, StatusCode::OK);
+
+        let entries = body["data"].as_array().unwrap();
+        let log_level = entries.iter().find(|e| e["key"] == "log_level").unwrap();
+        assert_eq!(log_level["value"], "debug");
+        assert_eq!(log_level["source"], "file");
+
+        let port = entries.iter().find(|e| e["key"] == "server.port").unwrap();
+        assert_eq!(port["source"], "default");
+    }
+
+    #[tokio::test]
+    async fn test_config_overrides() {
+        let dir = std::env::temp_dir().join(format!("blnav-overrides-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut config = AppConfig::default();
+        config.storage.data_dir = Some(dir.clone());
+        let server = TestServer::spawn_with(AppState::with_config(config), ServerConfig::default()).await;
+
+        let overrides = json!({ "log_level": "debug", "label.signing_key": "s3cret" });
+        let (status, body) = server
+            .send(Method::PUT, "/api/admin/config/overrides", Some(&overrides))
+            .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["log_level"], "debug");
+        assert_eq!(body["data"]["label.signing_key"], "***");
+
+        // 原样提交脱敏后的值时保留原密钥
+        let mut overrides = body["data"].clone();
+        overrides["server.port"] = json!(8081);
+        let (status, _) = server
+            .send(Method::PUT, "/api/admin/config/overrides", Some(&overrides))
+            .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let mut restarted = AppConfig::default();
+        restarted.storage.data_dir = Some(dir.clone());
+        restarted.apply_override_file().unwrap();
+        assert_eq!(restarted.label.signing_key.as_deref(), Some("s3cret"));
+        assert_eq!(restarted.server.port, 8081);
+
+        let (status, body) = server.get("/api/admin/config/overrides").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["server.port"], 8081);
+
+        for invalid in [json!({ "storage.data_dir": "/tmp" }), json!({ "server.port": "abc" })] {
+            let (status, body) = server
+                .send(Method::PUT, "/api/admin/config/overrides", Some(&invalid))
+                .await;
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+            assert_eq!(body["if_success"], false);
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_config_overrides_require_data_dir() {
+        let server = TestServer::spawn().await;
+
+        let (status, body) = server.get("/api/admin/config/overrides").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"], json!({}));
+
+        let overrides = json!({ "log_level": "debug" });
+        let (status, _) = server
+            .send(Method::PUT, "/api/admin/config/overrides", Some(&overrides))
+            .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_replay_journal() {
+        let server = TestServer::spawn().await;
+        let repo = server.state().beacon_repository();
+        repo.create(BeaconBuilder::new("beacon_100").build()).await.unwrap();
+        repo.delete(&"beacon_100".into()).await.unwrap();
+
+        let (status, body) = server.get("/api/admin/journal").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"].as_array().unwrap().len(), 2);
+        assert_eq!(body["data"][1]["mutation"]["op"], "delete_beacon");
+
+        let (status, body) = server.post_json("/api/admin/replay?up_to_seq=1", &json!({})).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["beacon_count"], 1);
+        assert!(repo.find_by_id(&"beacon_100".into()).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_manage_templates() {
+        let server = TestServer::spawn().await;
+        let template = json!({ "power": -65, "interval": 500 });
+
+        let (status, body) = server
+            .send(Method::PUT, "/api/admin/templates/warehouse", Some(&template))
+            .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["id"], "warehouse");
+
+        let (status, body) = server.get("/api/admin/templates").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"][0]["power"], -65);
+
+        let invalid = json!({ "interval": 0 });
+        let (status, _) = server.send(Method::PUT, "/api/admin/templates/bad", Some(&invalid)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = server.send(Method::DELETE, "/api/admin/templates/warehouse", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = server.get("/api/admin/templates/warehouse").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
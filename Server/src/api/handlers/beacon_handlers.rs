@@ -8,34 +8,326 @@ use axum::{
 };
 use std::sync::Arc;
 
-use crate::api::dto::{ApiResponse, BeaconDto};
+use crate::api::dto::{
+    ApiResponse, BeaconDto, CreateBeaconRequest, LabelVerifyQuery, LocationHistoryFilter, LocationVersionDto,
+};
+use crate::api::query::{Filter, JsonBody, Pagination, PathParam, TimeRange};
+use crate::application::BeaconCriteria;
+use crate::domain::{Beacon, BeaconId};
+use crate::error::{AppError, Result};
 use crate::infrastructure::AppState;
 
 /// 获取所有Beacon设备
+///
+/// 按ID排序分页，可按楼层、区域、状态过滤
 pub async fn get_all_beacons(
     State(state): State<Arc<AppState>>,
-) -> impl IntoResponse {
-    let repo = state.beacon_repository();
-    
-    match repo.find_all().await {
-        Ok(beacons) => {
-            let beacon_dtos: Vec<BeaconDto> = beacons.iter().map(BeaconDto::from).collect();
-            let response = ApiResponse::success(
-                "获取所有beacon设备成功".to_string(),
-                beacon_dtos,
-            );
-            (StatusCode::OK, Json(response)).into_response()
-        }
-        Err(e) => {
-            tracing::error!("Failed to fetch beacons: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({
-                    "status": 500,
-                    "if_success": false,
-                    "message": "Failed to fetch beacons"
-                })),
-            ).into_response()
-        }
+    pagination: Pagination,
+    Filter(criteria): Filter<BeaconCriteria>,
+) -> Result<impl IntoResponse> {
+    let beacons = state.beacon_service().list(&criteria).await?;
+
+    let beacon_dtos: Vec<BeaconDto> = beacons.iter().map(BeaconDto::from).collect();
+    let response = ApiResponse::paged(
+        "获取所有beacon设备成功".to_string(),
+        pagination.paginate(beacon_dtos),
+    );
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// 创建Beacon
+///
+/// 指定 `template_id` 时，请求中未填写的字段取自模板
+pub async fn create_beacon(
+    State(state): State<Arc<AppState>>,
+    JsonBody(request): JsonBody<CreateBeaconRequest>,
+) -> Result<impl IntoResponse> {
+    let template = state
+        .template_service()
+        .resolve(request.template_id.as_deref())
+        .await?;
+
+    let beacon = Beacon::try_from(request.into_dto(template.as_ref())?)?;
+
+    let beacon = state.beacon_service().create(beacon).await?;
+    let response = ApiResponse::success("创建beacon设备成功".to_string(), BeaconDto::from(&beacon));
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// 更新Beacon
+///
+/// 请求体为完整的Beacon，其中的 `id` 须与路径一致
+pub async fn update_beacon(
+    State(state): State<Arc<AppState>>,
+    PathParam(id): PathParam<BeaconId>,
+    JsonBody(dto): JsonBody<BeaconDto>,
+) -> Result<impl IntoResponse> {
+    if dto.id != id {
+        return Err(AppError::ValidationError(format!(
+            "Beacon id {} does not match path id {}",
+            dto.id, id
+        )));
+    }
+    let beacon = Beacon::try_from(dto)?;
+
+    let beacon = state.beacon_service().update(beacon).await?;
+    let response = ApiResponse::success("更新beacon设备成功".to_string(), BeaconDto::from(&beacon));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// 获取Beacon的位置历史
+///
+/// 指定 `at` 时只返回该时刻有效的版本，用于按观测时间重新处理历史数据；
+/// 指定时间范围时返回与范围重叠的版本
+pub async fn get_beacon_locations(
+    State(state): State<Arc<AppState>>,
+    PathParam(id): PathParam<BeaconId>,
+    pagination: Pagination,
+    range: TimeRange,
+    Filter(filter): Filter<LocationHistoryFilter>,
+) -> Result<impl IntoResponse> {
+    let versions = state.beacon_service().locations(&id, filter.at).await?;
+
+    let dtos: Vec<LocationVersionDto> = versions
+        .iter()
+        .filter(|version| range.overlaps(version.valid_from_ms, version.valid_to_ms))
+        .map(LocationVersionDto::from)
+        .collect();
+    let response = ApiResponse::paged("获取beacon位置历史成功".to_string(), pagination.paginate(dtos));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// 获取Beacon标签的二维码内容
+///
+/// 返回签名后的信标身份载荷及安装校验链接，由客户端渲染为二维码
+pub async fn get_beacon_qr(
+    State(state): State<Arc<AppState>>,
+    PathParam(id): PathParam<BeaconId>,
+) -> Result<impl IntoResponse> {
+    let label = state.beacon_service().label(&id, &state.config().label).await?;
+    let response = ApiResponse::success("生成beacon标签成功".to_string(), label);
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// 校验安装标签
+///
+/// 安装人员扫描二维码后访问，签名有效且与当前信标配置一致时返回该Beacon
+pub async fn verify_beacon_label(
+    State(state): State<Arc<AppState>>,
+    Filter(query): Filter<LabelVerifyQuery>,
+) -> Result<impl IntoResponse> {
+    let beacon = state
+        .beacon_service()
+        .verify_label(&query.token, &state.config().label)
+        .await?;
+    let response = ApiResponse::success("校验beacon标签成功".to_string(), BeaconDto::from(&beacon));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// 获取各灯具的整体健康状态
+///
+/// 同一灯具上的信标全部失效时标记为 `outage`，提示排查供电而非更换电池
+pub async fn get_fixture_health(
+    State(state): State<Arc<AppState>>,
+    pagination: Pagination,
+) -> Result<impl IntoResponse> {
+    let health = state.beacon_service().fixture_health().await?;
+    let response = ApiResponse::paged("获取灯具健康状态成功".to_string(), pagination.paginate(health));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::dto::BeaconDto;
+    use crate::config::{AppConfig, ServerConfig};
+    use crate::domain::BeaconId;
+    use crate::infrastructure::label::LabelSigner;
+    use crate::infrastructure::AppState;
+    use crate::testkit::{scenarios, BeaconBuilder, TestServer};
+    use hyper::{Method, StatusCode};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_get_all_beacons_paged() {
+        let server = TestServer::spawn_with_beacons(scenarios::two_floors()).await;
+
+        let (status, body) = server.get("/api/all_beacons?floor=2F&offset=1&limit=1").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"].as_array().unwrap().len(), 1);
+        assert_eq!(body["data"][0]["location"]["floor"], "2F");
+        assert_eq!(body["page"]["total"], 3);
+        assert_eq!(body["page"]["offset"], 1);
+
+        let (status, body) = server.get("/api/all_beacons?limit=0").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["if_success"], false);
+
+        let (status, body) = server.get("/api/all_beacons?offset=abc").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["if_success"], false);
+    }
+
+    #[tokio::test]
+    async fn test_get_fixture_health() {
+        let server = TestServer::spawn_with_beacons(vec![
+            BeaconBuilder::new("a1").fixture("fixture_a").inactive().build(),
+            BeaconBuilder::new("a2").fixture("fixture_a").inactive().build(),
+            BeaconBuilder::new("b1").fixture("fixture_b").build(),
+        ])
+        .await;
+
+        let (status, body) = server.get("/api/fixtures/health").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"][0]["fixture_id"], "fixture_a");
+        assert_eq!(body["data"][0]["status"], "outage");
+        assert_eq!(body["data"][1]["status"], "healthy");
+
+        let (_, body) = server.get("/api/all_beacons").await;
+        assert_eq!(body["data"][0]["fixture_id"], "fixture_a");
+    }
+
+    #[tokio::test]
+    async fn test_get_beacon_locations() {
+        let server = TestServer::spawn().await;
+        let repo = server.state().beacon_repository();
+        repo.create(BeaconBuilder::new("beacon_100").at(1.0, 1.0, 2.5).build()).await.unwrap();
+        repo.update(BeaconBuilder::new("beacon_100").at(6.0, 1.0, 2.5).build()).await.unwrap();
+
+        let (status, body) = server.get("/api/beacons/beacon_100/locations").await;
+        assert_eq!(status, StatusCode::OK);
+        let versions = body["data"].as_array().unwrap();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[1]["location"]["x"], 6.0);
+        assert!(versions[1]["valid_to_ms"].is_null());
+
+        let at = versions[0]["valid_from_ms"].as_u64().unwrap();
+        let (status, body) = server.get(&format!("/api/beacons/beacon_100/locations?at={}", at)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"].as_array().unwrap().len(), 1);
+
+        let (status, _) = server.get("/api/beacons/missing/locations").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        // 路径中的ID格式错误时返回统一的错误响应
+        let (status, body) = server.get("/api/beacons/beacon%20100/locations").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["if_success"], false);
+    }
+
+    #[tokio::test]
+    async fn test_get_beacon_qr() {
+        let mut config = AppConfig::default();
+        config.label.signing_key = Some("secret".to_string());
+        let server = TestServer::spawn_with(AppState::with_config(config), ServerConfig::default()).await;
+        server.state().beacon_repository().create(BeaconBuilder::new("beacon_100").build()).await.unwrap();
+
+        let (status, body) = server.get("/api/beacons/beacon_100/qr").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["payload"]["beacon_id"], "beacon_100");
+
+        let signer = LabelSigner::from_config(&server.state().config().label).unwrap();
+        let payload = signer.verify(body["data"]["token"].as_str().unwrap()).unwrap();
+        assert_eq!(payload.beacon_id, "beacon_100");
+
+        let (status, _) = server.get("/api/beacons/missing/qr").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_verify_beacon_label() {
+        let mut config = AppConfig::default();
+        config.label.signing_key = Some("secret".to_string());
+        let server = TestServer::spawn_with(AppState::with_config(config), ServerConfig::default()).await;
+        server.state().beacon_repository().create(BeaconBuilder::new("beacon_100").minor(7).build()).await.unwrap();
+
+        let (_, body) = server.get("/api/beacons/beacon_100/qr").await;
+        let token = body["data"]["token"].as_str().unwrap().to_string();
+
+        let (status, body) = server.get(&format!("/api/beacons/labels/verify?token={}", token)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["id"], "beacon_100");
+
+        let (status, _) = server.get("/api/beacons/labels/verify?token=forged.token").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        // 签发后修改了 Minor，标签已贴错
+        let moved = BeaconBuilder::new("beacon_100").minor(8).build();
+        server.state().beacon_repository().update(moved).await.unwrap();
+        let (status, _) = server.get(&format!("/api/beacons/labels/verify?token={}", token)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        server.state().beacon_repository().delete(&BeaconId::from("beacon_100")).await.unwrap();
+        let (status, _) = server.get(&format!("/api/beacons/labels/verify?token={}", token)).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_get_beacon_qr_requires_signing_key() {
+        let server = TestServer::spawn().await;
+        server.state().beacon_repository().create(BeaconBuilder::new("beacon_100").build()).await.unwrap();
+
+        let (status, _) = server.get("/api/beacons/beacon_100/qr").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_create_beacon_from_template() {
+        let server = TestServer::spawn().await;
+        let template = json!({
+            "uuid": "FDA50693-A4E2-4FB1-AFCF-C6EB07647825",
+            "power": -65,
+            "interval": 500,
+        });
+        server.send(Method::PUT, "/api/admin/templates/warehouse", Some(&template)).await;
+
+        let request = json!({
+            "id": "beacon_100",
+            "template_id": "warehouse",
+            "major": 1,
+            "minor": 2,
+            "interval": 1000,
+            "location": { "x": 1.0, "y": 2.0, "z": 3.0, "floor": "1F", "area_id": "area_001" },
+        });
+        let (status, body) = server.post_json("/api/beacons", &request).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["data"]["power"], -65);
+        assert_eq!(body["data"]["interval"], 1000);
+        assert_eq!(body["data"]["status"], "active");
+
+        let (status, _) = server.post_json("/api/beacons", &request).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let mut request = request;
+        request["id"] = json!("beacon_101");
+        request["template_id"] = json!("missing");
+        let (status, _) = server.post_json("/api/beacons", &request).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        // 请求体格式错误时同样返回统一的错误响应
+        let (status, body) = server.post_json("/api/beacons", &json!({ "id": 1 })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["if_success"], false);
+    }
+
+    #[tokio::test]
+    async fn test_update_beacon() {
+        let server = TestServer::spawn_with_beacons(vec![BeaconBuilder::new("beacon_100").build()]).await;
+        let mut beacon = serde_json::to_value(BeaconDto::from(BeaconBuilder::new("beacon_100").power(-70).build())).unwrap();
+
+        let (status, body) = server.send(Method::PUT, "/api/beacons/beacon_100", Some(&beacon)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["power"], -70);
+
+        let (status, _) = server.send(Method::PUT, "/api/beacons/beacon_101", Some(&beacon)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        beacon["power"] = json!(5);
+        let (status, _) = server.send(Method::PUT, "/api/beacons/beacon_100", Some(&beacon)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        beacon["id"] = json!("missing");
+        beacon["power"] = json!(-70);
+        let (status, _) = server.send(Method::PUT, "/api/beacons/missing", Some(&beacon)).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
     }
 }
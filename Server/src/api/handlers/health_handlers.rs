@@ -1,7 +1,11 @@
 //! 健康检查处理程序
 
-use axum::{http::StatusCode, response::IntoResponse, Json};
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
 use serde::Serialize;
+use std::sync::Arc;
+
+use crate::api::dto::{ApiResponse, CapabilitiesDto};
+use crate::infrastructure::AppState;
 
 #[derive(Serialize)]
 pub struct HealthResponse {
@@ -17,3 +21,59 @@ pub async fn health_check() -> impl IntoResponse {
     };
     (StatusCode::OK, Json(response))
 }
+
+/// 服务运行信息：启动时间、运行时长、重启次数与就绪状态变化
+pub async fn health_info(
+    State(state): State<Arc<AppState>>,
+) -> impl IntoResponse {
+    (StatusCode::OK, Json(state.health().info().await))
+}
+
+/// 服务能力：支持的算法、编码、子系统与请求限制
+pub async fn get_capabilities(
+    State(state): State<Arc<AppState>>,
+) -> impl IntoResponse {
+    let response = ApiResponse::success(
+        "获取服务能力成功".to_string(),
+        CapabilitiesDto::from_config(&state.config()),
+    );
+    (StatusCode::OK, Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{AppConfig, ServerConfig};
+    use crate::infrastructure::health::Readiness;
+    use crate::infrastructure::AppState;
+    use crate::testkit::TestServer;
+    use hyper::StatusCode;
+
+    #[tokio::test]
+    async fn test_health_info() {
+        let server = TestServer::spawn().await;
+        server.state().health().set_readiness(Readiness::Ready, "test").await;
+
+        let (status, body) = server.get("/health/info").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["readiness"], "ready");
+        assert_eq!(body["restart_count"], 0);
+        assert_eq!(body["transitions"][0]["from"], "starting");
+    }
+
+    #[tokio::test]
+    async fn test_get_capabilities() {
+        let mut config = AppConfig::default();
+        config.server.http2_enabled = false;
+        config.compression.enabled = false;
+        let server = TestServer::spawn_with(AppState::with_config(config), ServerConfig::default()).await;
+
+        let (status, body) = server.get("/api/capabilities").await;
+        assert_eq!(status, StatusCode::OK);
+        let data = &body["data"];
+        assert_eq!(data["http_versions"], serde_json::json!(["http/1.1"]));
+        assert!(data["response_encodings"].as_array().unwrap().is_empty());
+        assert_eq!(data["subsystems"]["jobs"], true);
+        assert_eq!(data["subsystems"]["labels"], false);
+        assert_eq!(data["limits"]["max_page_size"], 1000);
+    }
+}
@@ -0,0 +1,100 @@
+//! 后台任务处理程序
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use std::sync::Arc;
+
+use crate::api::dto::{ApiResponse, JobFilter};
+use crate::api::query::{Filter, Pagination, PathParam, TimeRange};
+use crate::error::Result;
+use crate::infrastructure::AppState;
+
+/// 列出后台任务，时间范围按创建时间过滤
+pub async fn list_jobs(
+    State(state): State<Arc<AppState>>,
+    pagination: Pagination,
+    range: TimeRange,
+    Filter(filter): Filter<JobFilter>,
+) -> impl IntoResponse {
+    let mut jobs = state.jobs().list(filter.status);
+    jobs.retain(|job| range.contains(job.created_at_ms));
+
+    let response = ApiResponse::paged("获取任务列表成功".to_string(), pagination.paginate(jobs));
+    (StatusCode::OK, Json(response))
+}
+
+/// 获取任务状态与进度
+pub async fn get_job(
+    State(state): State<Arc<AppState>>,
+    PathParam(id): PathParam<u64>,
+) -> Result<impl IntoResponse> {
+    let job = state.jobs().get(id)?;
+    let response = ApiResponse::success("获取任务成功".to_string(), job);
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// 获取已成功任务的结果
+pub async fn get_job_result(
+    State(state): State<Arc<AppState>>,
+    PathParam(id): PathParam<u64>,
+) -> Result<impl IntoResponse> {
+    let result = state.jobs().result(id)?;
+    let response = ApiResponse::success("获取任务结果成功".to_string(), result);
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// 取消任务
+pub async fn cancel_job(
+    State(state): State<Arc<AppState>>,
+    PathParam(id): PathParam<u64>,
+) -> Result<impl IntoResponse> {
+    let job = state.jobs().cancel(id)?;
+    tracing::info!("Job {} ({}) cancelled", job.id, job.kind);
+
+    let response = ApiResponse::success("任务已取消".to_string(), job);
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testkit::TestServer;
+    use hyper::StatusCode;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_job_lifecycle() {
+        let server = TestServer::spawn().await;
+        let jobs = server.state().jobs();
+        let job = jobs.spawn("test", |_| async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        });
+
+        let (status, body) = server.get(&format!("/api/jobs/{}", job.id)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["kind"], "test");
+
+        let (status, body) = server.post_json(&format!("/api/jobs/{}/cancel", job.id), &json!({})).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["status"], "cancelled");
+
+        let (status, _) = server.post_json(&format!("/api/jobs/{}/cancel", job.id), &json!({})).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) = server.get("/api/jobs?status=cancelled").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"].as_array().unwrap().len(), 1);
+
+        let (status, _) = server.get("/api/jobs/999").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, body) = server.get("/api/jobs/abc").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["if_success"], false);
+    }
+}
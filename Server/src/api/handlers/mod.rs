@@ -1,7 +1,13 @@
 //! API 处理程序
 
+pub mod admin_handlers;
 pub mod beacon_handlers;
 pub mod health_handlers;
+pub mod job_handlers;
+pub mod planning_handlers;
 
+pub use admin_handlers::*;
 pub use beacon_handlers::*;
 pub use health_handlers::*;
+pub use job_handlers::*;
+pub use planning_handlers::*;
@@ -0,0 +1,121 @@
+//! 部署规划处理程序
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use std::sync::Arc;
+
+use crate::api::dto::{ApiResponse, CoverageDto, CoverageQuery, CoverageRequest};
+use crate::api::query::{Filter, JsonBody};
+use crate::domain::coverage::CoverageSimulation;
+use crate::error::Result;
+use crate::infrastructure::AppState;
+
+/// 根据规划中的信标位置仿真覆盖情况
+///
+/// 指定 `async=true` 时作为后台任务执行，返回202和任务信息
+pub async fn simulate_coverage(
+    State(state): State<Arc<AppState>>,
+    Filter(query): Filter<CoverageQuery>,
+    JsonBody(request): JsonBody<CoverageRequest>,
+) -> Result<Response> {
+    let floor = request.floor.clone();
+    let simulation = CoverageSimulation::from(request);
+    let grid = simulation.grid;
+    let service = state.planning_service();
+
+    if query.run_async {
+        let job = service.submit(simulation, move |map| CoverageDto::new(floor, &grid, map))?;
+        let response = ApiResponse::success("覆盖仿真任务已创建".to_string(), job);
+        return Ok((StatusCode::ACCEPTED, Json(response)).into_response());
+    }
+
+    let map = service.simulate(simulation).await?;
+    let response = ApiResponse::success("覆盖仿真完成".to_string(), CoverageDto::new(floor, &grid, map));
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testkit::TestServer;
+    use hyper::StatusCode;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_simulate_coverage() {
+        let server = TestServer::spawn().await;
+        let request = json!({
+            "floor": "1F",
+            "beacons": [
+                { "id": "a", "x": 0.0, "y": 0.0, "z": 2.5, "power": -59 },
+                { "id": "b", "x": 10.0, "y": 0.0, "z": 2.5, "power": -59 },
+                { "id": "c", "x": 5.0, "y": 10.0, "z": 2.5, "power": -59 }
+            ],
+            "grid": {
+                "min_x": 0.0, "min_y": 0.0, "max_x": 10.0, "max_y": 10.0,
+                "cell_size": 2.0, "receiver_height": 1.2
+            }
+        });
+
+        let (status, body) = server.post_json("/api/planning/coverage", &request).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["rows"], 5);
+        assert_eq!(body["data"]["columns"], 5);
+        assert_eq!(body["data"]["covered_ratio"], 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_coverage_as_job() {
+        let server = TestServer::spawn().await;
+        let request = json!({
+            "beacons": [{ "id": "a", "x": 0.0, "y": 0.0, "z": 2.5, "power": -59 }],
+            "grid": {
+                "min_x": 0.0, "min_y": 0.0, "max_x": 10.0, "max_y": 10.0,
+                "cell_size": 2.0, "receiver_height": 1.2
+            },
+            "min_beacons": 1
+        });
+
+        let (status, body) = server.post_json("/api/planning/coverage?async=true", &request).await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+        let id = body["data"]["id"].as_u64().unwrap();
+
+        let mut job = body["data"].clone();
+        for _ in 0..100 {
+            if job["status"] == "succeeded" {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            job = server.get(&format!("/api/jobs/{}", id)).await.1["data"].clone();
+        }
+        assert_eq!(job["status"], "succeeded");
+
+        let (status, body) = server.get(job["result_url"].as_str().unwrap()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["rows"], 5);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_coverage_rejects_invalid_grid() {
+        let server = TestServer::spawn().await;
+        let request = json!({
+            "beacons": [{ "id": "a", "x": 0.0, "y": 0.0, "z": 2.5, "power": -59 }],
+            "grid": {
+                "min_x": 0.0, "min_y": 0.0, "max_x": 10.0, "max_y": 10.0,
+                "cell_size": 0.0, "receiver_height": 1.2
+            }
+        });
+
+        let (status, body) = server.post_json("/api/planning/coverage", &request).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["if_success"], false);
+
+        let (status, _) = server.post_json("/api/planning/coverage?async=true", &request).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(server.state().jobs().list(None).is_empty());
+    }
+}
@@ -0,0 +1,223 @@
+//! API 中间件
+
+use axum::{
+    body::{Body, Bytes, HttpBody},
+    extract::{ConnectInfo, Request, State},
+    http::{header, HeaderMap},
+    middleware::Next,
+    response::Response,
+};
+use http_body::{Frame, SizeHint};
+use serde_json::Value;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use crate::infrastructure::rejections::RejectedRequest;
+use crate::infrastructure::AppState;
+
+/// 解析顶层字段名、读取错误原因时允许缓冲的最大字节数
+const MAX_INSPECTED_BODY: u64 = 64 * 1024;
+
+/// 记录被拒绝（4xx）的请求
+///
+/// 较小的JSON请求体会被缓冲以提取顶层字段名，字段值不会被记录。
+/// 未声明 Content-Length 的请求（如分块传输）记录处理程序实际读取的字节数
+pub async fn record_rejections(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let at_ms = state.clock().now_ms();
+    let (parts, body) = request.into_parts();
+
+    let body_size = parts
+        .headers
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok());
+    let received = Arc::new(AtomicU64::new(0));
+    let (body, body_keys) = if is_json(&parts.headers) && body_size.is_some_and(|size| size <= MAX_INSPECTED_BODY) {
+        // 读取失败时交给处理程序按空请求体拒绝
+        let bytes = axum::body::to_bytes(body, MAX_INSPECTED_BODY as usize)
+            .await
+            .unwrap_or_default();
+        let keys = json_keys(&bytes);
+        (Body::from(bytes), keys)
+    } else {
+        let body = CountingBody { inner: body, received: Arc::clone(&received) };
+        (Body::new(body), Vec::new())
+    };
+
+    let mut rejected = RejectedRequest {
+        at_ms,
+        method: parts.method.to_string(),
+        path: parts.uri.path().to_string(),
+        query_keys: parts
+            .uri
+            .query()
+            .map(|query| {
+                query
+                    .split('&')
+                    .filter(|pair| !pair.is_empty())
+                    .map(|pair| pair.split('=').next().unwrap_or_default().to_string())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        source_ip: parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip().to_string()),
+        user_agent: parts
+            .headers
+            .get(header::USER_AGENT)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string),
+        body_size,
+        body_keys,
+        status: 0,
+        reason: String::new(),
+    };
+
+    let response = next.run(Request::from_parts(parts, body)).await;
+    let status = response.status();
+    if !status.is_client_error() {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let (body, message) = if body.size_hint().upper().is_some_and(|size| size <= MAX_INSPECTED_BODY) {
+        let bytes = axum::body::to_bytes(body, MAX_INSPECTED_BODY as usize)
+            .await
+            .unwrap_or_default();
+        let message = serde_json::from_slice::<Value>(&bytes)
+            .ok()
+            .and_then(|v| v.get("message")?.as_str().map(str::to_string));
+        (Body::from(bytes), message)
+    } else {
+        (body, None)
+    };
+
+    rejected.body_size = rejected.body_size.or(Some(received.load(Ordering::Relaxed)));
+    rejected.status = status.as_u16();
+    rejected.reason = message
+        .unwrap_or_else(|| status.canonical_reason().unwrap_or_default().to_string());
+    state.rejections().record(rejected);
+
+    Response::from_parts(parts, body)
+}
+
+/// 统计已读取字节数的请求体
+struct CountingBody {
+    inner: Body,
+    received: Arc<AtomicU64>,
+}
+
+impl HttpBody for CountingBody {
+    type Data = Bytes;
+    type Error = axum::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Bytes>, Self::Error>>> {
+        let poll = Pin::new(&mut self.inner).poll_frame(cx);
+        if let Poll::Ready(Some(Ok(frame))) = &poll {
+            if let Some(data) = frame.data_ref() {
+                self.received.fetch_add(data.len() as u64, Ordering::Relaxed);
+            }
+        }
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+fn is_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"))
+}
+
+/// JSON对象的顶层字段名，非对象时为空
+fn json_keys(bytes: &[u8]) -> Vec<String> {
+    match serde_json::from_slice::<Value>(bytes) {
+        Ok(Value::Object(map)) => map.keys().cloned().collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{AppConfig, ServerConfig};
+    use crate::infrastructure::AppState;
+    use crate::testkit::TestServer;
+    use http_body_util::Full;
+    use hyper::body::Bytes;
+    use hyper::header::{CONTENT_TYPE, TRANSFER_ENCODING};
+    use hyper::{Method, Request, StatusCode};
+    use serde_json::json;
+
+    async fn spawn(enabled: bool) -> TestServer {
+        let mut config = AppConfig::default();
+        config.rejections.enabled = enabled;
+        TestServer::spawn_with(AppState::with_config(config), ServerConfig::default()).await
+    }
+
+    #[tokio::test]
+    async fn test_rejected_requests_are_recorded() {
+        let server = spawn(true).await;
+        let request = json!({ "beacons": [], "grid": { "note": "s3cret" } });
+        let (status, _) = server.post_json("/api/planning/coverage?async=true", &request).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) = server.get("/health").await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, body) = server.get("/api/admin/rejections").await;
+        assert_eq!(status, StatusCode::OK);
+        let entries = body["data"].as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["path"], "/api/planning/coverage");
+        assert_eq!(entries[0]["query_keys"], json!(["async"]));
+        assert_eq!(entries[0]["body_keys"], json!(["beacons", "grid"]));
+        assert_eq!(entries[0]["source_ip"], "127.0.0.1");
+        assert!(!body.to_string().contains("s3cret"));
+
+        let (_, body) = server.get("/api/admin/rejections?status=404").await;
+        assert!(body["data"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_chunked_body_size() {
+        let server = spawn(true).await;
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/beacons")
+            .header(CONTENT_TYPE, "application/json")
+            .header(TRANSFER_ENCODING, "chunked")
+            .body(Full::new(Bytes::from_static(b"{\"id\": 1}")))
+            .unwrap();
+        let (status, _, _) = server.request(request).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let entries = server.state().rejections().query(&Default::default());
+        assert_eq!(entries[0].body_size, Some(9));
+    }
+
+    #[tokio::test]
+    async fn test_rejections_disabled_by_default() {
+        let server = spawn(false).await;
+        let (status, _) = server.get("/api/beacons/missing/locations").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(server.state().rejections().query(&Default::default()).is_empty());
+    }
+}
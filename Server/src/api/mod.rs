@@ -5,3 +5,5 @@
 pub mod routes;
 pub mod dto;
 pub mod handlers;
+pub mod middleware;
+pub mod query;
@@ -0,0 +1,223 @@
+//! 接口通用请求参数
+//!
+//! 所有列表接口使用相同的分页（`offset`、`limit`）、时间范围（`from_ms`、`to_ms`）参数，
+//! 路径参数与请求体分别通过 [`PathParam`]、[`JsonBody`] 解析。参数错误时与其他错误一样返回
+//! [`ErrorResponse`](crate::api::dto::ErrorResponse) 格式
+
+use axum::extract::{FromRequest, FromRequestParts, Json, Path, Query, Request};
+use axum::http::request::Parts;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AppError, Result};
+
+/// 默认每页条数
+const DEFAULT_LIMIT: usize = 100;
+
+/// 每页最大条数
+pub const MAX_LIMIT: usize = 1000;
+
+/// 分页参数
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Pagination {
+    #[serde(default)]
+    pub offset: usize,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+fn default_limit() -> usize {
+    DEFAULT_LIMIT
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self { offset: 0, limit: DEFAULT_LIMIT }
+    }
+}
+
+/// 分页信息
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub struct PageInfo {
+    pub offset: usize,
+    pub limit: usize,
+    /// 分页前的总条数
+    pub total: usize,
+}
+
+/// 一页数据
+#[derive(Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub info: PageInfo,
+}
+
+impl Pagination {
+    fn validate(&self) -> Result<()> {
+        if self.limit == 0 || self.limit > MAX_LIMIT {
+            return Err(AppError::ValidationError(format!(
+                "limit must be between 1 and {}",
+                MAX_LIMIT
+            )));
+        }
+        Ok(())
+    }
+
+    /// 截取当前页，调用方需保证 `items` 的顺序稳定
+    pub fn paginate<T>(&self, items: Vec<T>) -> Page<T> {
+        let total = items.len();
+        let items = items.into_iter().skip(self.offset).take(self.limit).collect();
+        Page {
+            items,
+            info: PageInfo { offset: self.offset, limit: self.limit, total },
+        }
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for Pagination {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self> {
+        let Filter(pagination) = Filter::<Pagination>::from_request_parts(parts, state).await?;
+        pagination.validate()?;
+        Ok(pagination)
+    }
+}
+
+/// 时间范围（Unix毫秒，左闭右开），两端均可省略
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct TimeRange {
+    pub from_ms: Option<u64>,
+    pub to_ms: Option<u64>,
+}
+
+impl TimeRange {
+    /// 时间点是否在范围内
+    pub fn contains(&self, at_ms: u64) -> bool {
+        self.from_ms.is_none_or(|from| at_ms >= from) && self.to_ms.is_none_or(|to| at_ms < to)
+    }
+
+    /// 区间 `[start, end)` 是否与范围重叠，`end` 为空表示尚未结束
+    pub fn overlaps(&self, start: u64, end: Option<u64>) -> bool {
+        self.to_ms.is_none_or(|to| start < to)
+            && self.from_ms.is_none_or(|from| end.is_none_or(|end| end > from))
+    }
+
+    fn validate(&self) -> Result<()> {
+        if let (Some(from), Some(to)) = (self.from_ms, self.to_ms) {
+            if from > to {
+                return Err(AppError::ValidationError(
+                    "from_ms must not be later than to_ms".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for TimeRange {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self> {
+        let Filter(range) = Filter::<TimeRange>::from_request_parts(parts, state).await?;
+        range.validate()?;
+        Ok(range)
+    }
+}
+
+/// 接口特有的查询条件
+///
+/// 与 [`Query`] 相同，但解析失败时返回统一的错误响应
+#[derive(Debug, Clone, Default)]
+pub struct Filter<T>(pub T);
+
+impl<S, T> FromRequestParts<S> for Filter<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self> {
+        Query::<T>::from_request_parts(parts, state)
+            .await
+            .map(|Query(value)| Self(value))
+            .map_err(|e| AppError::ValidationError(e.body_text()))
+    }
+}
+
+/// 路径参数
+///
+/// 与 [`Path`] 相同，但解析失败时返回统一的错误响应
+#[derive(Debug, Clone, Default)]
+pub struct PathParam<T>(pub T);
+
+impl<S, T> FromRequestParts<S> for PathParam<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + Send,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self> {
+        Path::<T>::from_request_parts(parts, state)
+            .await
+            .map(|Path(value)| Self(value))
+            .map_err(|e| AppError::ValidationError(e.body_text()))
+    }
+}
+
+/// JSON请求体
+///
+/// 与 [`Json`] 相同，但解析失败时返回统一的错误响应
+#[derive(Debug, Clone, Default)]
+pub struct JsonBody<T>(pub T);
+
+impl<S, T> FromRequest<S> for JsonBody<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = AppError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self> {
+        Json::<T>::from_request(request, state)
+            .await
+            .map(|Json(value)| Self(value))
+            .map_err(|e| AppError::ValidationError(e.body_text()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paginate() {
+        let pagination = Pagination { offset: 2, limit: 2 };
+        let page = pagination.paginate((0..5).collect());
+        assert_eq!(page.items, vec![2, 3]);
+        assert_eq!(page.info, PageInfo { offset: 2, limit: 2, total: 5 });
+
+        let page = Pagination { offset: 10, limit: 2 }.paginate(vec![1, 2, 3]);
+        assert!(page.items.is_empty());
+        assert_eq!(page.info.total, 3);
+
+        assert!(Pagination { offset: 0, limit: 0 }.validate().is_err());
+        assert!(Pagination { offset: 0, limit: MAX_LIMIT + 1 }.validate().is_err());
+    }
+
+    #[test]
+    fn test_time_range() {
+        let range = TimeRange { from_ms: Some(100), to_ms: Some(200) };
+        assert!(range.contains(100));
+        assert!(!range.contains(200));
+        assert!(range.overlaps(50, Some(150)));
+        assert!(range.overlaps(150, None));
+        assert!(!range.overlaps(0, Some(100)));
+        assert!(!range.overlaps(200, None));
+        assert!(TimeRange::default().contains(0));
+
+        assert!(TimeRange { from_ms: Some(2), to_ms: Some(1) }.validate().is_err());
+    }
+}
@@ -0,0 +1,31 @@
+//! 管理路由
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+use std::sync::Arc;
+
+use crate::api::handlers::{
+    delete_template, get_config_overrides, get_effective_config, get_journal, get_rejections,
+    get_template, list_templates, put_config_overrides, put_template, replay_journal,
+};
+use crate::infrastructure::AppState;
+
+/// 构建管理路由
+pub fn router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/api/admin/config/effective", get(get_effective_config))
+        .route(
+            "/api/admin/config/overrides",
+            get(get_config_overrides).put(put_config_overrides),
+        )
+        .route("/api/admin/journal", get(get_journal))
+        .route("/api/admin/rejections", get(get_rejections))
+        .route("/api/admin/replay", post(replay_journal))
+        .route("/api/admin/templates", get(list_templates))
+        .route(
+            "/api/admin/templates/{id}",
+            get(get_template).put(put_template).delete(delete_template),
+        )
+}
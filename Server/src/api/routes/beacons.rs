@@ -1,16 +1,25 @@
 //! Beacon 相关路由
 
 use axum::{
-    routing::get,
+    routing::{get, post, put},
     Router,
 };
 use std::sync::Arc;
 
-use crate::api::handlers::get_all_beacons;
+use crate::api::handlers::{
+    create_beacon, get_all_beacons, get_beacon_locations, get_beacon_qr, get_fixture_health,
+    update_beacon, verify_beacon_label,
+};
 use crate::infrastructure::AppState;
 
 /// 构建Beacon路由
 pub fn router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/api/all_beacons", get(get_all_beacons))
+        .route("/api/beacons", post(create_beacon))
+        .route("/api/beacons/{id}", put(update_beacon))
+        .route("/api/beacons/{id}/locations", get(get_beacon_locations))
+        .route("/api/beacons/{id}/qr", get(get_beacon_qr))
+        .route("/api/beacons/labels/verify", get(verify_beacon_label))
+        .route("/api/fixtures/health", get(get_fixture_health))
 }
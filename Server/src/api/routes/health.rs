@@ -6,11 +6,13 @@ use axum::{
 };
 use std::sync::Arc;
 
-use crate::api::handlers::health_check;
+use crate::api::handlers::{get_capabilities, health_check, health_info};
 use crate::infrastructure::AppState;
 
 /// 构建健康检查路由
 pub fn router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/health", get(health_check))
+        .route("/health/info", get(health_info))
+        .route("/api/capabilities", get(get_capabilities))
 }
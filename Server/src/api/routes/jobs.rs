@@ -0,0 +1,19 @@
+//! 后台任务路由
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+use std::sync::Arc;
+
+use crate::api::handlers::{cancel_job, get_job, get_job_result, list_jobs};
+use crate::infrastructure::AppState;
+
+/// 构建后台任务路由
+pub fn router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/api/jobs", get(list_jobs))
+        .route("/api/jobs/{id}", get(get_job))
+        .route("/api/jobs/{id}/result", get(get_job_result))
+        .route("/api/jobs/{id}/cancel", post(cancel_job))
+}
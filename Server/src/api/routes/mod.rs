@@ -1,4 +1,162 @@
 //! API 路由模块
 
+pub mod admin;
 pub mod health;
 pub mod beacons;
+pub mod jobs;
+pub mod planning;
+
+use axum::{middleware, Router};
+use std::sync::Arc;
+use tower_http::compression::predicate::{NotForContentType, Predicate, SizeAbove};
+use tower_http::compression::{CompressionLayer, CompressionLevel};
+use tower_http::cors::CorsLayer;
+use tower_http::decompression::RequestDecompressionLayer;
+
+use crate::api::middleware::record_rejections;
+use crate::config::{CompressionConfig, CompressionQuality};
+use crate::infrastructure::AppState;
+
+/// 构建完整的应用路由
+pub fn app(state: Arc<AppState>) -> Router {
+    let config = state.config();
+
+    let mut router = Router::new()
+        .merge(health::router())
+        .merge(beacons::router())
+        .merge(planning::router())
+        .merge(jobs::router())
+        .merge(admin::router());
+
+    // 位于解压之后，记录到的请求体字段与处理程序看到的一致
+    if config.rejections.enabled {
+        router = router.layer(middleware::from_fn_with_state(Arc::clone(&state), record_rejections));
+    }
+
+    if config.compression.enabled {
+        router = router.layer(compression_layer(&config.compression));
+    }
+
+    if config.compression.decompress_requests {
+        router = router.layer(RequestDecompressionLayer::new().gzip(true).br(true));
+    }
+
+    router
+        .layer(CorsLayer::permissive())
+        .with_state(state)
+}
+
+/// 构建响应压缩层
+///
+/// 除最小长度外，与默认策略一样跳过gRPC、图片和SSE流式响应
+fn compression_layer(config: &CompressionConfig) -> CompressionLayer<impl Predicate> {
+    let level = match config.level {
+        CompressionQuality::Fastest => CompressionLevel::Fastest,
+        CompressionQuality::Default => CompressionLevel::Default,
+        CompressionQuality::Best => CompressionLevel::Best,
+        CompressionQuality::Precise(level) => CompressionLevel::Precise(level),
+    };
+
+    let predicate = SizeAbove::new(config.min_size)
+        .and(NotForContentType::GRPC)
+        .and(NotForContentType::IMAGES)
+        .and(NotForContentType::SSE);
+
+    CompressionLayer::new()
+        .gzip(true)
+        .br(true)
+        .quality(level)
+        .compress_when(predicate)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{AppConfig, ServerConfig};
+    use crate::infrastructure::AppState;
+    use crate::testkit::TestServer;
+    use flate2::read::GzDecoder;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use http_body_util::Full;
+    use hyper::body::Bytes;
+    use hyper::header::{ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_TYPE};
+    use hyper::{Request, StatusCode};
+    use serde_json::{json, Value};
+    use std::io::{Read, Write};
+
+    /// 响应体足够大、会触发压缩的覆盖仿真请求
+    fn coverage_request() -> Value {
+        json!({
+            "beacons": [
+                { "id": "a", "x": 0.0, "y": 0.0, "z": 2.5, "power": -59 },
+                { "id": "b", "x": 20.0, "y": 0.0, "z": 2.5, "power": -59 },
+                { "id": "c", "x": 10.0, "y": 20.0, "z": 2.5, "power": -59 }
+            ],
+            "grid": {
+                "min_x": 0.0, "min_y": 0.0, "max_x": 20.0, "max_y": 20.0,
+                "cell_size": 1.0, "receiver_height": 1.2
+            }
+        })
+    }
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn gunzip(data: &[u8]) -> Vec<u8> {
+        let mut decoded = Vec::new();
+        GzDecoder::new(data).read_to_end(&mut decoded).unwrap();
+        decoded
+    }
+
+    fn gzip_coverage_request() -> Request<Full<Bytes>> {
+        Request::post("/api/planning/coverage")
+            .header(CONTENT_TYPE, "application/json")
+            .header(CONTENT_ENCODING, "gzip")
+            .header(ACCEPT_ENCODING, "gzip")
+            .body(Full::new(Bytes::from(gzip(coverage_request().to_string().as_bytes()))))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_gzip_request_and_response() {
+        let server = TestServer::spawn().await;
+        let (status, headers, body) = server.request(gzip_coverage_request()).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers[CONTENT_ENCODING], "gzip");
+        let value: Value = serde_json::from_slice(&gunzip(&body)).unwrap();
+        assert_eq!(value["data"]["rows"], 20);
+    }
+
+    #[tokio::test]
+    async fn test_small_response_not_compressed() {
+        let server = TestServer::spawn().await;
+        let request = Request::get("/health")
+            .header(ACCEPT_ENCODING, "gzip")
+            .body(Full::new(Bytes::new()))
+            .unwrap();
+        let (status, headers, body) = server.request(request).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(!headers.contains_key(CONTENT_ENCODING));
+        let value: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(value["status"], "healthy");
+    }
+
+    #[tokio::test]
+    async fn test_compression_disabled() {
+        let mut config = AppConfig::default();
+        config.compression.enabled = false;
+        let server = TestServer::spawn_with(AppState::with_config(config), ServerConfig::default()).await;
+        let (status, headers, body) = server.request(gzip_coverage_request()).await;
+
+        // 请求体仍然会被解压，但响应不压缩
+        assert_eq!(status, StatusCode::OK);
+        assert!(!headers.contains_key(CONTENT_ENCODING));
+        let value: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(value["data"]["columns"], 20);
+    }
+}
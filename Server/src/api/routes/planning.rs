@@ -0,0 +1,16 @@
+//! 部署规划路由
+
+use axum::{
+    routing::post,
+    Router,
+};
+use std::sync::Arc;
+
+use crate::api::handlers::simulate_coverage;
+use crate::infrastructure::AppState;
+
+/// 构建部署规划路由
+pub fn router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/api/planning/coverage", post(simulate_coverage))
+}
@@ -0,0 +1,124 @@
+//! 管理应用服务
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::config::{self, AppConfig, ConfigEntry, ConfigOverrides, OVERRIDES_FILE, REDACTED};
+use crate::error::{AppError, Result};
+use crate::infrastructure::journal::{Journal, JournalEntry, ReplaySummary};
+use crate::infrastructure::rejections::{RejectedRequest, RejectionFilter, RejectionLog};
+use crate::infrastructure::repository::BeaconRepository;
+
+/// 管理应用服务
+///
+/// 提供配置查询与覆盖、变更日志查询与重放、被拒绝请求查询等运维用例
+pub struct AdminService {
+    config: Arc<AppConfig>,
+    journal: Arc<Journal>,
+    beacons: Arc<BeaconRepository>,
+    rejections: Arc<RejectionLog>,
+}
+
+impl AdminService {
+    pub fn new(
+        config: Arc<AppConfig>,
+        journal: Arc<Journal>,
+        beacons: Arc<BeaconRepository>,
+        rejections: Arc<RejectionLog>,
+    ) -> Self {
+        Self {
+            config,
+            journal,
+            beacons,
+            rejections,
+        }
+    }
+
+    /// 当前生效的全部配置项及其来源，敏感值已脱敏
+    pub fn effective_config(&self) -> Vec<ConfigEntry> {
+        self.config.effective()
+    }
+
+    /// 已保存的配置覆盖，敏感值已脱敏
+    ///
+    /// 未配置持久化目录时为空
+    pub async fn config_overrides(&self) -> Result<ConfigOverrides> {
+        let mut overrides = match self.overrides_path() {
+            Some(path) => read_blocking(path).await?,
+            None => ConfigOverrides::new(),
+        };
+        for (key, value) in overrides.iter_mut() {
+            if config::is_secret(key) {
+                *value = REDACTED.into();
+            }
+        }
+        Ok(overrides)
+    }
+
+    /// 整体替换已保存的配置覆盖，重启后生效
+    ///
+    /// 敏感项的值为脱敏标记时保留原值，以便修改其他项时原样提交查询结果
+    pub async fn save_config_overrides(&self, mut overrides: ConfigOverrides) -> Result<ConfigOverrides> {
+        let path = self.overrides_path().ok_or_else(|| {
+            AppError::BusinessError("Config overrides require storage.data_dir".to_string())
+        })?;
+
+        let current = read_blocking(path.clone()).await?;
+        for (key, value) in overrides.iter_mut() {
+            if config::is_secret(key) && *value == REDACTED {
+                match current.get(key) {
+                    Some(stored) => *value = stored.clone(),
+                    None => {
+                        return Err(AppError::ValidationError(format!(
+                            "No stored value for {}",
+                            key
+                        )))
+                    }
+                }
+            }
+        }
+        AppConfig::default().apply_overrides(&overrides)?;
+
+        let saved = overrides.clone();
+        tokio::task::spawn_blocking(move || config::write_overrides(&path, &saved))
+            .await
+            .map_err(|e| AppError::InternalError(format!("Config overrides writer failed: {}", e)))??;
+        tracing::warn!("Config overrides saved ({} keys), effective after restart", overrides.len());
+        self.config_overrides().await
+    }
+
+    fn overrides_path(&self) -> Option<PathBuf> {
+        self.config
+            .storage
+            .data_dir
+            .as_ref()
+            .map(|dir| dir.join(OVERRIDES_FILE))
+    }
+
+    /// 变更日志中的全部条目，按序号排序
+    pub async fn journal(&self) -> Result<Vec<JournalEntry>> {
+        self.journal.entries().await
+    }
+
+    /// 重放变更日志，用重放结果替换当前Beacon数据
+    pub async fn replay(&self, up_to_seq: Option<u64>) -> Result<ReplaySummary> {
+        let summary = self.beacons.replay_journal(up_to_seq).await?;
+        tracing::warn!(
+            "Beacon data replaced by journal replay (up_to_seq: {:?}, beacons: {})",
+            summary.up_to_seq,
+            summary.beacon_count
+        );
+        Ok(summary)
+    }
+
+    /// 按条件查询被拒绝的请求
+    pub fn rejections(&self, filter: &RejectionFilter) -> Vec<RejectedRequest> {
+        self.rejections.query(filter)
+    }
+}
+
+async fn read_blocking(path: PathBuf) -> Result<ConfigOverrides> {
+    tokio::task::spawn_blocking(move || config::read_overrides(&path))
+        .await
+        .map_err(|e| AppError::InternalError(format!("Config overrides reader failed: {}", e)))?
+}
@@ -0,0 +1,247 @@
+//! Beacon 应用服务
+
+use serde::Deserialize;
+use std::future::Future;
+use std::sync::Arc;
+
+use crate::config::LabelConfig;
+use crate::domain::fixture::{self, FixtureHealth};
+use crate::domain::{Beacon, BeaconId, LocationHistory, LocationVersion};
+use crate::error::{AppError, Result};
+use crate::infrastructure::clock::Clock;
+use crate::infrastructure::label::{LabelSigner, SignedLabel};
+use crate::infrastructure::repository::BeaconRepository;
+
+/// Beacon 数据来源
+pub trait BeaconStore: Send + Sync {
+    fn find_all(&self) -> impl Future<Output = Result<Vec<Beacon>>> + Send;
+
+    fn find_by_id(&self, id: &BeaconId) -> impl Future<Output = Result<Option<Beacon>>> + Send;
+
+    /// 保存新的Beacon，同ID的Beacon已存在时返回错误
+    fn create(&self, beacon: Beacon) -> impl Future<Output = Result<Beacon>> + Send;
+
+    /// 更新已有的Beacon，Beacon不存在时返回 `NotFound`
+    fn update(&self, beacon: Beacon) -> impl Future<Output = Result<Beacon>> + Send;
+
+    /// Beacon的位置历史，Beacon不存在时返回 `NotFound`
+    fn location_history(&self, id: &BeaconId) -> impl Future<Output = Result<LocationHistory>> + Send;
+}
+
+impl BeaconStore for BeaconRepository {
+    async fn find_all(&self) -> Result<Vec<Beacon>> {
+        BeaconRepository::find_all(self).await
+    }
+
+    async fn find_by_id(&self, id: &BeaconId) -> Result<Option<Beacon>> {
+        BeaconRepository::find_by_id(self, id).await
+    }
+
+    async fn create(&self, beacon: Beacon) -> Result<Beacon> {
+        BeaconRepository::create(self, beacon).await
+    }
+
+    async fn update(&self, beacon: Beacon) -> Result<Beacon> {
+        BeaconRepository::update(self, beacon).await
+    }
+
+    async fn location_history(&self, id: &BeaconId) -> Result<LocationHistory> {
+        BeaconRepository::location_history(self, id).await
+    }
+}
+
+/// Beacon 查询条件
+#[derive(Debug, Default, Deserialize)]
+pub struct BeaconCriteria {
+    pub floor: Option<String>,
+    pub area_id: Option<String>,
+    pub status: Option<String>,
+}
+
+impl BeaconCriteria {
+    pub fn matches(&self, beacon: &Beacon) -> bool {
+        self.floor.as_ref().is_none_or(|floor| &beacon.location.floor == floor)
+            && self.area_id.as_ref().is_none_or(|area| &beacon.location.area_id == area)
+            && self.status.as_ref().is_none_or(|status| &beacon.status == status)
+    }
+}
+
+/// Beacon 应用服务
+pub struct BeaconService<S: BeaconStore> {
+    store: Arc<S>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<S: BeaconStore> BeaconService<S> {
+    pub fn new(store: Arc<S>, clock: Arc<dyn Clock>) -> Self {
+        Self { store, clock }
+    }
+
+    /// 按条件查询Beacon，按ID排序
+    pub async fn list(&self, criteria: &BeaconCriteria) -> Result<Vec<Beacon>> {
+        let mut beacons = self.store.find_all().await?;
+        beacons.retain(|beacon| criteria.matches(beacon));
+        beacons.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(beacons)
+    }
+
+    /// 获取Beacon，不存在时返回 `NotFound`
+    pub async fn get(&self, id: &BeaconId) -> Result<Beacon> {
+        self.store
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Beacon with id {} not found", id)))
+    }
+
+    /// 创建Beacon，同ID的Beacon已存在时返回 `BusinessError`
+    pub async fn create(&self, beacon: Beacon) -> Result<Beacon> {
+        self.store.create(beacon).await
+    }
+
+    /// 更新Beacon，不存在时返回 `NotFound`
+    pub async fn update(&self, beacon: Beacon) -> Result<Beacon> {
+        self.store.update(beacon).await
+    }
+
+    /// 位置历史；指定 `at_ms` 时只返回该时刻有效的版本
+    pub async fn locations(&self, id: &BeaconId, at_ms: Option<u64>) -> Result<Vec<LocationVersion>> {
+        let history = self.store.location_history(id).await?;
+        Ok(match at_ms {
+            Some(at) => history.at(at).cloned().into_iter().collect(),
+            None => history.versions().to_vec(),
+        })
+    }
+
+    /// 各灯具的整体健康状态
+    pub async fn fixture_health(&self) -> Result<Vec<FixtureHealth>> {
+        let beacons = self.store.find_all().await?;
+        Ok(fixture::fixture_health(&beacons))
+    }
+
+    /// 为Beacon签发二维码标签
+    pub async fn label(&self, id: &BeaconId, config: &LabelConfig) -> Result<SignedLabel> {
+        let signer = LabelSigner::from_config(config)?;
+        let beacon = self.get(id).await?;
+        signer.sign(&beacon, self.clock.now_ms())
+    }
+
+    /// 校验安装人员扫到的标签，返回对应的Beacon
+    ///
+    /// 标签签发后信标的 UUID/Major/Minor 被修改时视为贴错，返回 `ValidationError`
+    pub async fn verify_label(&self, token: &str, config: &LabelConfig) -> Result<Beacon> {
+        let payload = LabelSigner::from_config(config)?.verify(token)?;
+        let beacon = self.get(&payload.beacon_id).await?;
+        if beacon.uuid != payload.uuid || beacon.major != payload.major || beacon.minor != payload.minor {
+            return Err(AppError::ValidationError(format!(
+                "Label does not match beacon {}, reprint the label",
+                beacon.id
+            )));
+        }
+        Ok(beacon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::ManualClock;
+    use crate::testkit::BeaconBuilder;
+
+    /// 不依赖仓储实现的内存数据源
+    struct FixedStore(Vec<Beacon>);
+
+    impl BeaconStore for FixedStore {
+        async fn find_all(&self) -> Result<Vec<Beacon>> {
+            Ok(self.0.clone())
+        }
+
+        async fn find_by_id(&self, id: &BeaconId) -> Result<Option<Beacon>> {
+            Ok(self.0.iter().find(|b| &b.id == id).cloned())
+        }
+
+        async fn create(&self, beacon: Beacon) -> Result<Beacon> {
+            match self.find_by_id(&beacon.id).await? {
+                Some(_) => Err(AppError::BusinessError(beacon.id.to_string())),
+                None => Ok(beacon),
+            }
+        }
+
+        async fn update(&self, beacon: Beacon) -> Result<Beacon> {
+            match self.find_by_id(&beacon.id).await? {
+                Some(_) => Ok(beacon),
+                None => Err(AppError::NotFound(beacon.id.to_string())),
+            }
+        }
+
+        async fn location_history(&self, id: &BeaconId) -> Result<LocationHistory> {
+            let beacon = self
+                .find_by_id(id)
+                .await?
+                .ok_or_else(|| AppError::NotFound(id.to_string()))?;
+            let mut history = LocationHistory::default();
+            history.record(beacon.location, 100);
+            Ok(history)
+        }
+    }
+
+    fn service() -> BeaconService<FixedStore> {
+        let store = FixedStore(vec![
+            BeaconBuilder::new("b").floor("2F").build(),
+            BeaconBuilder::new("a").floor("1F").build(),
+            BeaconBuilder::new("c").floor("2F").inactive().build(),
+        ]);
+        BeaconService::new(Arc::new(store), Arc::new(ManualClock::new(5_000)))
+    }
+
+    #[tokio::test]
+    async fn test_list() {
+        let service = service();
+        let ids: Vec<BeaconId> = service
+            .list(&BeaconCriteria::default())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|b| b.id)
+            .collect();
+        assert_eq!(ids, vec!["a", "b", "c"]);
+
+        let criteria = BeaconCriteria {
+            floor: Some("2F".to_string()),
+            status: Some("active".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(service.list(&criteria).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_locations_and_label() {
+        let service = service();
+        assert_eq!(service.locations(&"a".into(), None).await.unwrap().len(), 1);
+        assert!(service.locations(&"a".into(), Some(99)).await.unwrap().is_empty());
+        assert!(matches!(service.get(&"missing".into()).await, Err(AppError::NotFound(_))));
+
+        let config = LabelConfig {
+            signing_key: Some("secret".to_string()),
+            ..Default::default()
+        };
+        let label = service.label(&"a".into(), &config).await.unwrap();
+        assert_eq!(label.payload.issued_at_ms, 5_000);
+        assert!(service.label(&"a".into(), &LabelConfig::default()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_and_update() {
+        let service = service();
+        assert!(service.create(BeaconBuilder::new("d").build()).await.is_ok());
+        assert!(matches!(
+            service.create(BeaconBuilder::new("a").build()).await,
+            Err(AppError::BusinessError(_))
+        ));
+
+        assert!(service.update(BeaconBuilder::new("a").power(-70).build()).await.is_ok());
+        assert!(matches!(
+            service.update(BeaconBuilder::new("d").build()).await,
+            Err(AppError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,14 @@
+//! 应用服务层
+//!
+//! 位于接口层与仓储、算法之间，实现与传输方式无关的用例。
+//! HTTP处理程序只负责参数解析与响应格式，其他入口（如gRPC、MQTT）复用同一套服务
+
+pub mod admin_service;
+pub mod beacon_service;
+pub mod planning_service;
+pub mod template_service;
+
+pub use admin_service::AdminService;
+pub use beacon_service::{BeaconCriteria, BeaconService, BeaconStore};
+pub use planning_service::PlanningService;
+pub use template_service::TemplateService;
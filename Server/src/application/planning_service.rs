@@ -0,0 +1,56 @@
+//! 部署规划应用服务
+
+use std::sync::Arc;
+
+use crate::domain::coverage::{CoverageMap, CoverageSimulation};
+use crate::error::{AppError, Result};
+use crate::infrastructure::jobs::{Job, JobManager, JobProgress};
+
+/// 部署规划应用服务
+pub struct PlanningService {
+    jobs: Arc<JobManager>,
+}
+
+impl PlanningService {
+    pub fn new(jobs: Arc<JobManager>) -> Self {
+        Self { jobs }
+    }
+
+    /// 执行覆盖仿真
+    pub async fn simulate(&self, simulation: CoverageSimulation) -> Result<CoverageMap> {
+        // 大网格的计算量较大，放到阻塞线程池中执行
+        run_blocking(move || simulation.run()).await
+    }
+
+    /// 作为后台任务执行覆盖仿真，`finish` 将仿真结果转换为任务结果
+    ///
+    /// 参数无效时直接返回错误，不产生失败的任务
+    pub fn submit<T, F>(&self, simulation: CoverageSimulation, finish: F) -> Result<Job>
+    where
+        T: serde::Serialize,
+        F: FnOnce(CoverageMap) -> T + Send + 'static,
+    {
+        simulation.validate()?;
+        Ok(self.jobs.spawn("coverage_simulation", move |progress| async move {
+            // 任务取消后阻塞线程中的计算不会被中断，每行网格检查一次是否已取消
+            run_blocking(move || simulation.run_with(|done| report(&progress, done)))
+                .await
+                .map(finish)
+        }))
+    }
+}
+
+/// 上报仿真进度，返回是否继续计算
+fn report(progress: &JobProgress, done: f64) -> bool {
+    if progress.is_cancelled() {
+        return false;
+    }
+    progress.set(done);
+    true
+}
+
+async fn run_blocking(f: impl FnOnce() -> Result<CoverageMap> + Send + 'static) -> Result<CoverageMap> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| AppError::InternalError(format!("Coverage simulation failed: {}", e)))?
+}
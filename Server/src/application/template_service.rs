@@ -0,0 +1,80 @@
+//! Beacon 配置模板应用服务
+
+use std::sync::Arc;
+
+use crate::domain::BeaconTemplate;
+use crate::error::{AppError, Result};
+use crate::infrastructure::repository::TemplateRepository;
+
+/// Beacon 配置模板应用服务
+pub struct TemplateService {
+    repo: Arc<TemplateRepository>,
+}
+
+impl TemplateService {
+    pub fn new(repo: Arc<TemplateRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// 获取所有模板，按ID排序
+    pub async fn list(&self) -> Result<Vec<BeaconTemplate>> {
+        self.repo.find_all().await
+    }
+
+    /// 获取模板，不存在时返回 `NotFound`
+    pub async fn get(&self, id: &str) -> Result<BeaconTemplate> {
+        self.repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Template with id {} not found", id)))
+    }
+
+    /// 以 `id` 保存模板，模板自身的 `id` 被忽略
+    pub async fn save(&self, id: String, template: BeaconTemplate) -> Result<BeaconTemplate> {
+        self.repo.save(BeaconTemplate { id, ..template }).await
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        self.repo.delete(id).await
+    }
+
+    /// 查找创建Beacon时引用的模板
+    ///
+    /// 引用的模板不存在属于请求参数错误，返回 `ValidationError` 而非 `NotFound`
+    pub async fn resolve(&self, id: Option<&str>) -> Result<Option<BeaconTemplate>> {
+        let Some(id) = id else {
+            return Ok(None);
+        };
+        match self.repo.find_by_id(id).await? {
+            Some(template) => Ok(Some(template)),
+            None => Err(AppError::ValidationError(format!("Template with id {} not found", id))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_save_and_resolve() {
+        let service = TemplateService::new(Arc::new(TemplateRepository::new()));
+        let template = BeaconTemplate {
+            id: "ignored".to_string(),
+            power: Some(-65),
+            ..Default::default()
+        };
+
+        let saved = service.save("warehouse".to_string(), template).await.unwrap();
+        assert_eq!(saved.id, "warehouse");
+        assert_eq!(service.get("warehouse").await.unwrap().power, Some(-65));
+        assert!(matches!(service.get("ignored").await, Err(AppError::NotFound(_))));
+
+        assert!(service.resolve(None).await.unwrap().is_none());
+        assert!(service.resolve(Some("warehouse")).await.unwrap().is_some());
+        assert!(matches!(
+            service.resolve(Some("missing")).await,
+            Err(AppError::ValidationError(_))
+        ));
+    }
+}
@@ -1,12 +1,236 @@
 //! 应用配置模块
 //!
-//! 提供应用的全局配置
+//! 提供应用的全局配置。配置按以下顺序逐层覆盖：
+//! 内置默认值 → 配置文件（JSON，路径由 `BLNAV_CONFIG` 指定）→ `BLNAV_` 前缀的环境变量 → 命令行参数
+//! → 管理接口设置的覆盖（保存在持久化目录下的 `config_overrides.json`，重启后生效）。
+//! 环境变量名由配置项名转换而来，例如 `server.port` 对应 `BLNAV_SERVER_PORT`。
+
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::error::{AppError, Result};
+
+/// 指定配置文件路径的环境变量
+pub const CONFIG_FILE_ENV: &str = "BLNAV_CONFIG";
+
+/// 配置项环境变量前缀
+const ENV_PREFIX: &str = "BLNAV_";
+
+/// 管理接口保存的配置覆盖文件名，位于持久化目录下
+pub const OVERRIDES_FILE: &str = "config_overrides.json";
+
+/// 敏感配置项对外展示的值
+pub const REDACTED: &str = "***";
+
+/// 管理接口设置的配置覆盖，键为配置项名
+pub type ConfigOverrides = BTreeMap<String, Value>;
+
+/// 可配置项定义
+///
+/// 读写、环境变量与生效配置的展示均由该表驱动
+struct ConfigKey {
+    name: &'static str,
+    /// 敏感配置项在对外展示时会被脱敏
+    secret: bool,
+    /// 可否通过管理接口覆盖
+    overridable: bool,
+    /// 以字符串形式设置
+    set: fn(&mut AppConfig, &str) -> std::result::Result<(), InvalidValue>,
+    /// 读取当前值
+    get: fn(&AppConfig) -> Value,
+}
+
+/// 配置值无法解析
+struct InvalidValue;
+
+/// 所有可配置项
+const CONFIG_KEYS: &[ConfigKey] = &[
+    ConfigKey {
+        name: "server.host",
+        secret: false,
+        overridable: true,
+        set: |c, raw| text(raw).map(|v| c.server.host = v),
+        get: |c| Value::from(c.server.host.clone()),
+    },
+    ConfigKey {
+        name: "server.port",
+        secret: false,
+        overridable: true,
+        set: |c, raw| parse(raw).map(|v| c.server.port = v),
+        get: |c| Value::from(c.server.port),
+    },
+    ConfigKey {
+        name: "server.http2_enabled",
+        secret: false,
+        overridable: true,
+        set: |c, raw| parse(raw).map(|v| c.server.http2_enabled = v),
+        get: |c| Value::from(c.server.http2_enabled),
+    },
+    ConfigKey {
+        name: "server.keep_alive",
+        secret: false,
+        overridable: true,
+        set: |c, raw| parse(raw).map(|v| c.server.keep_alive = v),
+        get: |c| Value::from(c.server.keep_alive),
+    },
+    ConfigKey {
+        name: "server.keep_alive_timeout_secs",
+        secret: false,
+        overridable: true,
+        set: |c, raw| parse(raw).map(|v| c.server.keep_alive_timeout_secs = v),
+        get: |c| Value::from(c.server.keep_alive_timeout_secs),
+    },
+    ConfigKey {
+        name: "server.http2_keep_alive_interval_secs",
+        secret: false,
+        overridable: true,
+        set: |c, raw| parse(raw).map(|v| c.server.http2_keep_alive_interval_secs = v),
+        get: |c| Value::from(c.server.http2_keep_alive_interval_secs),
+    },
+    ConfigKey {
+        name: "server.http2_max_concurrent_streams",
+        secret: false,
+        overridable: true,
+        set: |c, raw| parse(raw).map(|v| c.server.http2_max_concurrent_streams = v),
+        get: |c| Value::from(c.server.http2_max_concurrent_streams),
+    },
+    ConfigKey {
+        name: "compression.enabled",
+        secret: false,
+        overridable: true,
+        set: |c, raw| parse(raw).map(|v| c.compression.enabled = v),
+        get: |c| Value::from(c.compression.enabled),
+    },
+    ConfigKey {
+        name: "compression.level",
+        secret: false,
+        overridable: true,
+        set: |c, raw| parse(raw).map(|v| c.compression.level = v),
+        get: |c| Value::from(c.compression.level.to_string()),
+    },
+    ConfigKey {
+        name: "compression.min_size",
+        secret: false,
+        overridable: true,
+        set: |c, raw| parse(raw).map(|v| c.compression.min_size = v),
+        get: |c| Value::from(c.compression.min_size),
+    },
+    ConfigKey {
+        name: "compression.decompress_requests",
+        secret: false,
+        overridable: true,
+        set: |c, raw| parse(raw).map(|v| c.compression.decompress_requests = v),
+        get: |c| Value::from(c.compression.decompress_requests),
+    },
+    ConfigKey {
+        name: "storage.data_dir",
+        secret: false,
+        overridable: false,
+        set: |c, raw| optional_path(raw).map(|v| c.storage.data_dir = v),
+        get: |c| path_value(&c.storage.data_dir),
+    },
+    ConfigKey {
+        name: "seed.demo_data",
+        secret: false,
+        overridable: true,
+        set: |c, raw| parse(raw).map(|v| c.seed.demo_data = v),
+        get: |c| Value::from(c.seed.demo_data),
+    },
+    ConfigKey {
+        name: "seed.fixture_file",
+        secret: false,
+        overridable: true,
+        set: |c, raw| optional_path(raw).map(|v| c.seed.fixture_file = v),
+        get: |c| path_value(&c.seed.fixture_file),
+    },
+    ConfigKey {
+        name: "label.signing_key",
+        secret: true,
+        overridable: true,
+        set: |c, raw| optional_text(raw).map(|v| c.label.signing_key = v),
+        get: |c| Value::from(c.label.signing_key.clone()),
+    },
+    ConfigKey {
+        name: "label.install_link_base",
+        secret: false,
+        overridable: true,
+        set: |c, raw| text(raw).map(|v| c.label.install_link_base = v),
+        get: |c| Value::from(c.label.install_link_base.clone()),
+    },
+    ConfigKey {
+        name: "rejections.enabled",
+        secret: false,
+        overridable: true,
+        set: |c, raw| parse(raw).map(|v| c.rejections.enabled = v),
+        get: |c| Value::from(c.rejections.enabled),
+    },
+    ConfigKey {
+        name: "rejections.max_entries",
+        secret: false,
+        overridable: true,
+        set: |c, raw| parse(raw).map(|v| c.rejections.max_entries = v),
+        get: |c| Value::from(c.rejections.max_entries),
+    },
+    ConfigKey {
+        name: "rejections.max_age_secs",
+        secret: false,
+        overridable: true,
+        set: |c, raw| parse(raw).map(|v| c.rejections.max_age_secs = v),
+        get: |c| Value::from(c.rejections.max_age_secs),
+    },
+    ConfigKey {
+        name: "log_level",
+        secret: false,
+        overridable: true,
+        set: |c, raw| text(raw).map(|v| c.log_level = v),
+        get: |c| Value::from(c.log_level.clone()),
+    },
+];
+
+/// 配置项来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigSource {
+    /// 内置默认值
+    Default,
+    /// 配置文件
+    File,
+    /// 环境变量
+    Env,
+    /// 命令行参数
+    Cli,
+    /// 管理接口设置的覆盖
+    Admin,
+}
+
+/// 生效中的单个配置项
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigEntry {
+    pub key: &'static str,
+    pub value: Value,
+    pub source: ConfigSource,
+    pub secret: bool,
+}
 
 /// 服务器配置
-#[allow(dead_code)]
 pub struct ServerConfig {
     pub host: String,
     pub port: u16,
+    /// 是否接受HTTP/2连接（明文h2c，与HTTP/1.1自动协商）
+    pub http2_enabled: bool,
+    /// HTTP/1.1 是否启用连接保持
+    pub keep_alive: bool,
+    /// 空闲连接等待下一个请求的超时时间（秒）
+    pub keep_alive_timeout_secs: u64,
+    /// HTTP/2 PING 保活间隔（秒），为0时不发送
+    pub http2_keep_alive_interval_secs: u64,
+    /// HTTP/2 单连接最大并发流数
+    pub http2_max_concurrent_streams: u32,
 }
 
 impl Default for ServerConfig {
@@ -14,22 +238,167 @@ impl Default for ServerConfig {
         Self {
             host: "127.0.0.1".to_string(),
             port: 3000,
+            http2_enabled: true,
+            keep_alive: true,
+            keep_alive_timeout_secs: 75,
+            http2_keep_alive_interval_secs: 30,
+            http2_max_concurrent_streams: 256,
+        }
+    }
+}
+
+impl ServerConfig {
+    /// 空闲连接超时
+    pub fn keep_alive_timeout(&self) -> Duration {
+        Duration::from_secs(self.keep_alive_timeout_secs)
+    }
+
+    /// HTTP/2 PING 保活间隔
+    pub fn http2_keep_alive_interval(&self) -> Option<Duration> {
+        match self.http2_keep_alive_interval_secs {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    }
+}
+
+/// 压缩级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionQuality {
+    Fastest,
+    Default,
+    Best,
+    /// 具体数值，含义取决于压缩算法
+    Precise(i32),
+}
+
+impl FromStr for CompressionQuality {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "fastest" => Ok(Self::Fastest),
+            "default" => Ok(Self::Default),
+            "best" => Ok(Self::Best),
+            other => other
+                .parse()
+                .map(Self::Precise)
+                .map_err(|_| format!("unknown compression level: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for CompressionQuality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fastest => write!(f, "fastest"),
+            Self::Default => write!(f, "default"),
+            Self::Best => write!(f, "best"),
+            Self::Precise(level) => write!(f, "{}", level),
+        }
+    }
+}
+
+/// HTTP 压缩配置
+pub struct CompressionConfig {
+    /// 是否按 Accept-Encoding 压缩响应（gzip/br）
+    pub enabled: bool,
+    /// 压缩级别
+    pub level: CompressionQuality,
+    /// 小于该字节数的响应不压缩
+    pub min_size: u16,
+    /// 是否解压 Content-Encoding 为 gzip/br 的请求体
+    pub decompress_requests: bool,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            level: CompressionQuality::Default,
+            min_size: 1024,
+            decompress_requests: true,
+        }
+    }
+}
+
+/// 本地存储配置
+#[derive(Default)]
+pub struct StorageConfig {
+    /// 持久化运行数据的目录，未设置时仅保存在内存中
+    pub data_dir: Option<PathBuf>,
+}
+
+/// 初始数据配置
+#[derive(Default)]
+pub struct SeedConfig {
+    /// 启动时写入内置演示数据
+    pub demo_data: bool,
+    /// 启动时从该JSON文件写入Beacon数据
+    pub fixture_file: Option<PathBuf>,
+}
+
+/// 信标标签（二维码）配置
+pub struct LabelConfig {
+    /// 二维码载荷的签名密钥，未设置时无法生成标签
+    pub signing_key: Option<String>,
+    /// 安装校验链接的前缀（默认指向 `/api/beacons/labels/verify`），签名后的载荷作为 `token` 参数附加在后面
+    pub install_link_base: String,
+}
+
+impl Default for LabelConfig {
+    fn default() -> Self {
+        Self {
+            signing_key: None,
+            install_link_base: "https://blnav.local/api/beacons/labels/verify".to_string(),
+        }
+    }
+}
+
+/// 被拒绝请求记录配置
+pub struct RejectionsConfig {
+    /// 是否记录被拒绝（4xx）的请求
+    pub enabled: bool,
+    /// 最多保留的记录数
+    pub max_entries: usize,
+    /// 记录保留时长（秒）
+    pub max_age_secs: u64,
+}
+
+impl Default for RejectionsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_entries: 10_000,
+            max_age_secs: 7 * 24 * 3600,
         }
     }
 }
 
 /// 应用配置
-#[allow(dead_code)]
 pub struct AppConfig {
     pub server: ServerConfig,
+    pub compression: CompressionConfig,
+    pub storage: StorageConfig,
+    pub seed: SeedConfig,
+    pub label: LabelConfig,
+    pub rejections: RejectionsConfig,
     pub log_level: String,
+    /// 非默认值配置项的来源
+    sources: BTreeMap<&'static str, ConfigSource>,
 }
 
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
             server: ServerConfig::default(),
+            compression: CompressionConfig::default(),
+            storage: StorageConfig::default(),
+            seed: SeedConfig::default(),
+            label: LabelConfig::default(),
+            rejections: RejectionsConfig::default(),
             log_level: "info".to_string(),
+            sources: BTreeMap::new(),
         }
     }
 }
@@ -40,8 +409,395 @@ impl AppConfig {
         Self::default()
     }
 
-    #[allow(dead_code)]
+    /// 从配置文件和进程环境变量加载配置
+    pub fn load() -> Result<Self> {
+        let file = match std::env::var(CONFIG_FILE_ENV) {
+            Ok(path) => {
+                let content = std::fs::read_to_string(&path).map_err(|e| {
+                    AppError::ValidationError(format!("Failed to read config file {}: {}", path, e))
+                })?;
+                let value = serde_json::from_str(&content).map_err(|e| {
+                    AppError::ValidationError(format!("Invalid config file {}: {}", path, e))
+                })?;
+                Some(value)
+            }
+            Err(_) => None,
+        };
+
+        Self::load_from(file.as_ref(), std::env::vars())
+    }
+
+    /// 依次应用配置文件内容与环境变量
+    pub fn load_from(
+        file: Option<&Value>,
+        env: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Self> {
+        let mut config = Self::default();
+
+        if let Some(file) = file {
+            let mut values = Vec::new();
+            flatten_json("", file, &mut values)?;
+            for (key, value) in values {
+                config.set(&key, &raw_value(&value), ConfigSource::File)?;
+            }
+        }
+
+        let env: BTreeMap<String, String> = env.into_iter().collect();
+        for key in CONFIG_KEYS {
+            if let Some(raw) = env.get(&env_var_name(key.name)) {
+                config.set(key.name, raw, ConfigSource::Env)?;
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// 应用命令行参数
+    ///
+    /// 支持 `--seed-demo` 与 `--seed-file <path>`（或 `--seed-file=<path>`）
+    pub fn apply_args(&mut self, args: impl IntoIterator<Item = String>) -> Result<()> {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--seed-demo" => self.set("seed.demo_data", "true", ConfigSource::Cli)?,
+                "--seed-file" => {
+                    let path = args.next().ok_or_else(|| {
+                        AppError::ValidationError("--seed-file requires a path".to_string())
+                    })?;
+                    self.set("seed.fixture_file", &path, ConfigSource::Cli)?;
+                }
+                _ => match arg.strip_prefix("--seed-file=") {
+                    Some(path) => self.set("seed.fixture_file", path, ConfigSource::Cli)?,
+                    None => {
+                        return Err(AppError::ValidationError(format!(
+                            "Unknown argument: {}",
+                            arg
+                        )))
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+
     pub fn addr(&self) -> String {
         format!("{}:{}", self.server.host, self.server.port)
     }
+
+    /// 配置项来源
+    pub fn source_of(&self, key: &str) -> ConfigSource {
+        self.sources.get(key).copied().unwrap_or(ConfigSource::Default)
+    }
+
+    /// 当前生效的全部配置项，敏感值已脱敏
+    pub fn effective(&self) -> Vec<ConfigEntry> {
+        CONFIG_KEYS
+            .iter()
+            .map(|key| ConfigEntry {
+                key: key.name,
+                value: if key.secret {
+                    Value::from(REDACTED)
+                } else {
+                    (key.get)(self)
+                },
+                source: self.source_of(key.name),
+                secret: key.secret,
+            })
+            .collect()
+    }
+
+    /// 应用管理接口设置的配置覆盖
+    ///
+    /// `storage.data_dir` 决定覆盖文件的位置，不可覆盖
+    pub fn apply_overrides(&mut self, overrides: &ConfigOverrides) -> Result<()> {
+        for (key, value) in overrides {
+            if !find_key(key)?.overridable {
+                return Err(AppError::ValidationError(format!(
+                    "Configuration key {} cannot be overridden",
+                    key
+                )));
+            }
+            self.set(key, &raw_value(value), ConfigSource::Admin)?;
+        }
+        Ok(())
+    }
+
+    /// 读取持久化目录下的配置覆盖并应用，未配置持久化目录时不做任何事
+    pub fn apply_override_file(&mut self) -> Result<()> {
+        let Some(dir) = &self.storage.data_dir else {
+            return Ok(());
+        };
+        let overrides = read_overrides(&dir.join(OVERRIDES_FILE))?;
+        self.apply_overrides(&overrides)
+    }
+
+    /// 以字符串形式设置配置项
+    fn set(&mut self, key: &str, raw: &str, source: ConfigSource) -> Result<()> {
+        let entry = find_key(key)?;
+        (entry.set)(self, raw).map_err(|_| {
+            AppError::ValidationError(format!("Invalid value for {}: {}", key, raw))
+        })?;
+        self.sources.insert(entry.name, source);
+        Ok(())
+    }
+}
+
+/// 配置项是否为敏感项，未知配置项视为非敏感
+pub fn is_secret(key: &str) -> bool {
+    find_key(key).is_ok_and(|entry| entry.secret)
+}
+
+/// 读取配置覆盖文件，文件不存在时返回空
+pub fn read_overrides(path: &Path) -> Result<ConfigOverrides> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(ConfigOverrides::new()),
+        Err(e) => {
+            return Err(AppError::ValidationError(format!(
+                "Failed to read config overrides {}: {}",
+                path.display(),
+                e
+            )))
+        }
+    };
+    serde_json::from_str(&content).map_err(|e| {
+        AppError::ValidationError(format!("Invalid config overrides {}: {}", path.display(), e))
+    })
+}
+
+/// 写入配置覆盖文件，先写临时文件再替换，避免留下不完整的文件
+pub fn write_overrides(path: &Path, overrides: &ConfigOverrides) -> Result<()> {
+    let io_error = |e: std::io::Error| {
+        AppError::DatabaseError(format!("Failed to write config overrides {}: {}", path.display(), e))
+    };
+    let content = serde_json::to_vec_pretty(overrides)
+        .map_err(|e| AppError::InternalError(format!("Failed to encode config overrides: {}", e)))?;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let temp = path.with_extension("json.tmp");
+    std::fs::write(&temp, content).map_err(io_error)?;
+    std::fs::rename(&temp, path).map_err(io_error)
+}
+
+fn find_key(key: &str) -> Result<&'static ConfigKey> {
+    CONFIG_KEYS
+        .iter()
+        .find(|entry| entry.name == key)
+        .ok_or_else(|| AppError::ValidationError(format!("Unknown configuration key: {}", key)))
+}
+
+/// JSON值转为配置项的字符串形式，`null` 表示未设置
+fn raw_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// 配置项对应的环境变量名
+fn env_var_name(key: &str) -> String {
+    format!("{}{}", ENV_PREFIX, key.replace('.', "_").to_uppercase())
+}
+
+fn parse<T: FromStr>(raw: &str) -> std::result::Result<T, InvalidValue> {
+    raw.trim().parse().map_err(|_| InvalidValue)
+}
+
+fn text(raw: &str) -> std::result::Result<String, InvalidValue> {
+    Ok(raw.to_string())
+}
+
+/// 空字符串表示未设置
+fn optional_text(raw: &str) -> std::result::Result<Option<String>, InvalidValue> {
+    Ok(Some(raw.to_string()).filter(|text| !text.is_empty()))
+}
+
+/// 空字符串表示未设置
+fn optional_path(raw: &str) -> std::result::Result<Option<PathBuf>, InvalidValue> {
+    Ok(match raw.trim() {
+        "" => None,
+        path => Some(PathBuf::from(path)),
+    })
+}
+
+fn path_value(path: &Option<PathBuf>) -> Value {
+    match path {
+        Some(path) => Value::from(path.display().to_string()),
+        None => Value::Null,
+    }
+}
+
+/// 将嵌套的JSON对象展开为 `a.b` 形式的配置项
+fn flatten_json(prefix: &str, value: &Value, out: &mut Vec<(String, Value)>) -> Result<()> {
+    match value {
+        Value::Object(map) => {
+            for (name, child) in map {
+                let key = if prefix.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{}.{}", prefix, name)
+                };
+                flatten_json(&key, child, out)?;
+            }
+            Ok(())
+        }
+        _ if prefix.is_empty() => Err(AppError::ValidationError(
+            "Config file must contain a JSON object".to_string(),
+        )),
+        other => {
+            out.push((prefix.to_string(), other.clone()));
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_defaults() {
+        let config = AppConfig::load_from(None, Vec::new()).unwrap();
+        assert_eq!(config.addr(), "127.0.0.1:3000");
+        assert!(config.effective().iter().all(|e| e.source == ConfigSource::Default));
+    }
+
+    #[test]
+    fn test_layering() {
+        let file = json!({
+            "server": { "host": "0.0.0.0", "port": 8080 },
+            "log_level": "debug"
+        });
+        let config = AppConfig::load_from(
+            Some(&file),
+            env(&[("BLNAV_SERVER_PORT", "9090"), ("UNRELATED", "x")]),
+        )
+        .unwrap();
+
+        assert_eq!(config.server.host, "0.0.0.0");
+        assert_eq!(config.server.port, 9090);
+        assert_eq!(config.log_level, "debug");
+        assert_eq!(config.source_of("server.host"), ConfigSource::File);
+        assert_eq!(config.source_of("server.port"), ConfigSource::Env);
+        assert_eq!(config.source_of("server.keep_alive"), ConfigSource::Default);
+
+        let port = config.effective().into_iter().find(|e| e.key == "server.port").unwrap();
+        assert_eq!(port.value, json!(9090));
+        assert_eq!(port.source, ConfigSource::Env);
+    }
+
+    #[test]
+    fn test_compression_level() {
+        assert_eq!("best".parse(), Ok(CompressionQuality::Best));
+        assert_eq!(" Fastest ".parse(), Ok(CompressionQuality::Fastest));
+        assert_eq!("6".parse(), Ok(CompressionQuality::Precise(6)));
+        assert!("ultra".parse::<CompressionQuality>().is_err());
+
+        let config = AppConfig::load_from(None, env(&[("BLNAV_COMPRESSION_LEVEL", "9")])).unwrap();
+        assert_eq!(config.compression.level, CompressionQuality::Precise(9));
+    }
+
+    #[test]
+    fn test_apply_args() {
+        let mut config = AppConfig::load_from(None, env(&[("BLNAV_SEED_FIXTURE_FILE", "a.json")])).unwrap();
+        assert!(!config.seed.demo_data);
+
+        config
+            .apply_args(vec!["--seed-demo".to_string(), "--seed-file=b.json".to_string()])
+            .unwrap();
+        assert!(config.seed.demo_data);
+        assert_eq!(config.seed.fixture_file, Some(PathBuf::from("b.json")));
+        assert_eq!(config.source_of("seed.fixture_file"), ConfigSource::Cli);
+
+        assert!(config.apply_args(vec!["--seed-file".to_string()]).is_err());
+        assert!(config.apply_args(vec!["--unknown".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_secret_is_redacted() {
+        let config = AppConfig::load_from(None, env(&[("BLNAV_LABEL_SIGNING_KEY", "s3cret")])).unwrap();
+        assert_eq!(config.label.signing_key.as_deref(), Some("s3cret"));
+
+        let key = config.effective().into_iter().find(|e| e.key == "label.signing_key").unwrap();
+        assert!(key.secret);
+        assert_eq!(key.value, json!("***"));
+        assert_eq!(key.source, ConfigSource::Env);
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        assert!(AppConfig::load_from(None, env(&[("BLNAV_SERVER_PORT", "abc")])).is_err());
+        assert!(AppConfig::load_from(Some(&json!({ "server": { "unknown": 1 } })), Vec::new()).is_err());
+        assert!(AppConfig::load_from(Some(&json!([1, 2])), Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_key_table() {
+        let config = AppConfig::default();
+        for entry in config.effective() {
+            assert!(find_key(entry.key).is_ok());
+        }
+        assert_eq!(config.effective().len(), CONFIG_KEYS.len());
+        assert!(is_secret("label.signing_key"));
+        assert!(!is_secret("server.port"));
+    }
+
+    #[test]
+    fn test_admin_overrides() {
+        let mut config = AppConfig::load_from(None, env(&[("BLNAV_SERVER_PORT", "9090")])).unwrap();
+        config.apply_args(vec!["--seed-demo".to_string()]).unwrap();
+
+        let overrides: ConfigOverrides = serde_json::from_value(json!({
+            "server.port": 8081,
+            "seed.demo_data": false,
+            "label.signing_key": "s3cret",
+        }))
+        .unwrap();
+        config.apply_overrides(&overrides).unwrap();
+        assert_eq!(config.server.port, 8081);
+        assert!(!config.seed.demo_data);
+        assert_eq!(config.source_of("server.port"), ConfigSource::Admin);
+        assert_eq!(config.source_of("seed.demo_data"), ConfigSource::Admin);
+
+        // `null` 取消可选配置项
+        config.apply_overrides(&[("label.signing_key".to_string(), Value::Null)].into()).unwrap();
+        assert!(config.label.signing_key.is_none());
+
+        let data_dir = [("storage.data_dir".to_string(), json!("/tmp"))].into();
+        assert!(config.apply_overrides(&data_dir).is_err());
+        let invalid = [("server.port".to_string(), json!("abc"))].into();
+        assert!(config.apply_overrides(&invalid).is_err());
+        let unknown = [("server.unknown".to_string(), json!(1))].into();
+        assert!(config.apply_overrides(&unknown).is_err());
+    }
+
+    #[test]
+    fn test_override_file() {
+        let dir = std::env::temp_dir().join(format!("blnav-config-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let mut config = AppConfig::load_from(None, Vec::new()).unwrap();
+        config.storage.data_dir = Some(dir.clone());
+        // 覆盖文件不存在时不做任何事
+        config.apply_override_file().unwrap();
+        assert_eq!(config.source_of("log_level"), ConfigSource::Default);
+
+        let overrides = [("log_level".to_string(), json!("debug"))].into();
+        write_overrides(&dir.join(OVERRIDES_FILE), &overrides).unwrap();
+        assert_eq!(read_overrides(&dir.join(OVERRIDES_FILE)).unwrap(), overrides);
+
+        config.apply_override_file().unwrap();
+        assert_eq!(config.log_level, "debug");
+        assert_eq!(config.source_of("log_level"), ConfigSource::Admin);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
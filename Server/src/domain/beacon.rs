@@ -1,15 +1,107 @@
 //! Beacon 蓝牙信标模型
 
 use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
+use std::fmt;
+use std::str::FromStr;
 use crate::domain::location::Location;
 
+/// Beacon ID最大长度
+const MAX_ID_LEN: usize = 64;
+
+/// Beacon 设备ID
+///
+/// 序列化为普通字符串。允许字母、数字及 `_`、`-`、`:`、`.`，
+/// 可容纳自定义编号、MAC地址和UUID等常见格式。
+/// 外部输入通过 `parse` 或 `TryFrom<String>` 解析，反序列化（含路径参数）时同样验证
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct BeaconId(String);
+
+impl BeaconId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 验证ID格式
+    ///
+    /// 代码中的字面量通过 `From<&str>` 构造时不验证，由 [`Beacon::validate`] 统一检查
+    pub fn validate(&self) -> crate::error::Result<()> {
+        let id = self.as_str();
+        if id.is_empty() {
+            return Err(crate::error::AppError::ValidationError(
+                "Beacon ID cannot be empty".to_string(),
+            ));
+        }
+
+        if id.len() > MAX_ID_LEN
+            || !id.chars().all(|c| c.is_ascii_alphanumeric() || "_-:.".contains(c))
+        {
+            return Err(crate::error::AppError::ValidationError(format!(
+                "Invalid beacon ID {:?}: expected at most {} letters, digits or _-:.",
+                id, MAX_ID_LEN
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&str> for BeaconId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl TryFrom<String> for BeaconId {
+    type Error = crate::error::AppError;
+
+    fn try_from(id: String) -> crate::error::Result<Self> {
+        let id = Self(id);
+        id.validate()?;
+        Ok(id)
+    }
+}
+
+impl FromStr for BeaconId {
+    type Err = crate::error::AppError;
+
+    fn from_str(id: &str) -> crate::error::Result<Self> {
+        Self::try_from(id.to_string())
+    }
+}
+
+impl From<BeaconId> for String {
+    fn from(id: BeaconId) -> Self {
+        id.0
+    }
+}
+
+impl Borrow<str> for BeaconId {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<&str> for BeaconId {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Display for BeaconId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Beacon 设备信息
 ///
 /// 表示一个BLE信标设备的完整信息
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Beacon {
     /// 设备唯一ID
-    pub id: String,
+    pub id: BeaconId,
     /// UUID
     pub uuid: String,
     /// Major值
@@ -24,12 +116,16 @@ pub struct Beacon {
     pub interval: i32,
     /// 设备状态
     pub status: String,
+    /// 所在灯具（安装点），同一灯具上的信标共用供电
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixture_id: Option<String>,
 }
 
 impl Beacon {
     /// 创建新的Beacon
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
-        id: String,
+        id: BeaconId,
         uuid: String,
         major: i32,
         minor: i32,
@@ -47,17 +143,14 @@ impl Beacon {
             power,
             interval,
             status,
+            fixture_id: None,
         }
     }
 
     /// 验证Beacon数据的有效性
     #[allow(dead_code)]
     pub fn validate(&self) -> crate::error::Result<()> {
-        if self.id.is_empty() {
-            return Err(crate::error::AppError::ValidationError(
-                "Beacon ID cannot be empty".to_string(),
-            ));
-        }
+        self.id.validate()?;
 
         if self.uuid.is_empty() {
             return Err(crate::error::AppError::ValidationError(
@@ -82,7 +175,6 @@ impl Beacon {
     }
 
     /// 检查设备是否活跃
-    #[allow(dead_code)]
     pub fn is_active(&self) -> bool {
         self.status.to_lowercase() == "active"
     }
@@ -95,7 +187,7 @@ mod tests {
     fn create_test_beacon() -> Beacon {
         let loc = Location::new(100.0, 200.0, 150.0, "1F".to_string(), "area_001".to_string());
         Beacon::new(
-            "beacon_001".to_string(),
+            "beacon_001".into(),
             "FDA50693-A4E2-4FB1-AFCF-C6EB07647825".to_string(),
             10000,
             12345,
@@ -120,7 +212,7 @@ mod tests {
 
         let loc = Location::new(100.0, 200.0, 150.0, "1F".to_string(), "area_001".to_string());
         let invalid_beacon = Beacon::new(
-            "".to_string(),
+            "".into(),
             "FDA50693-A4E2-4FB1-AFCF-C6EB07647825".to_string(),
             10000,
             12345,
@@ -131,4 +223,30 @@ mod tests {
         );
         assert!(invalid_beacon.validate().is_err());
     }
+
+    #[test]
+    fn test_beacon_id_format() {
+        assert!(BeaconId::from("beacon_001").validate().is_ok());
+        assert!(BeaconId::from("C3:00:00:1A:2B:3C").validate().is_ok());
+        assert!(BeaconId::from("fda50693-a4e2-4fb1-afcf-c6eb07647825").validate().is_ok());
+        assert!(BeaconId::from("beacon 001").validate().is_err());
+        assert!(BeaconId::from("b".repeat(MAX_ID_LEN + 1).as_str()).validate().is_err());
+
+        let id: BeaconId = serde_json::from_str("\"beacon_001\"").unwrap();
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"beacon_001\"");
+    }
+
+    #[test]
+    fn test_beacon_id_parse() {
+        assert_eq!("beacon_001".parse::<BeaconId>().unwrap(), "beacon_001");
+        assert!("".parse::<BeaconId>().is_err());
+        assert!(BeaconId::try_from("beacon 001".to_string()).is_err());
+
+        // 反序列化时同样验证
+        assert!(serde_json::from_str::<BeaconId>("\"beacon/001\"").is_err());
+        assert!(serde_json::from_str::<Beacon>(
+            &serde_json::to_string(&create_test_beacon()).unwrap().replace("beacon_001", "")
+        )
+        .is_err());
+    }
 }
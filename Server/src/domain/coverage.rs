@@ -0,0 +1,409 @@
+//! 信标覆盖仿真
+//!
+//! 根据规划中的信标位置和对数距离路径损耗模型，
+//! 计算网格中每个单元可接收到的信标数量及水平精度因子（HDOP）
+
+use serde::{Deserialize, Serialize};
+use crate::domain::Beacon;
+use crate::error::{AppError, Result};
+
+/// 单次仿真允许的最大网格单元数
+pub const MAX_GRID_CELLS: usize = 250_000;
+
+/// 规划中的信标
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PlannedBeacon {
+    /// 信标标识
+    pub id: String,
+    /// X坐标（单位：米）
+    pub x: f64,
+    /// Y坐标（单位：米）
+    pub y: f64,
+    /// Z坐标/安装高度（单位：米）
+    pub z: f64,
+    /// 1米处参考信号强度（dBm）
+    pub power: i32,
+}
+
+impl From<&Beacon> for PlannedBeacon {
+    /// 以已部署信标的当前位置作为规划位置，楼层与区域信息不参与仿真
+    fn from(beacon: &Beacon) -> Self {
+        Self {
+            id: beacon.id.as_str().to_string(),
+            x: beacon.location.x,
+            y: beacon.location.y,
+            z: beacon.location.z,
+            power: beacon.power,
+        }
+    }
+}
+
+/// 对数距离路径损耗模型
+///
+/// `rssi(d) = power - 10 * n * log10(d)`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct PathLossModel {
+    /// 路径损耗指数 n
+    pub exponent: f64,
+}
+
+impl PathLossModel {
+    /// 预测距离 `distance` 米处的信号强度
+    pub fn predict_rssi(&self, power: i32, distance: f64) -> f64 {
+        // 1米以内按参考功率处理，避免对数发散
+        power as f64 - 10.0 * self.exponent * distance.max(1.0).log10()
+    }
+}
+
+impl Default for PathLossModel {
+    fn default() -> Self {
+        Self { exponent: 2.0 }
+    }
+}
+
+/// 仿真网格范围
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct CoverageGrid {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+    /// 单元边长（单位：米）
+    pub cell_size: f64,
+    /// 接收设备高度（单位：米）
+    pub receiver_height: f64,
+}
+
+impl CoverageGrid {
+    /// 列数
+    pub fn columns(&self) -> usize {
+        ((self.max_x - self.min_x) / self.cell_size).ceil() as usize
+    }
+
+    /// 行数
+    pub fn rows(&self) -> usize {
+        ((self.max_y - self.min_y) / self.cell_size).ceil() as usize
+    }
+
+    /// 单元中心坐标
+    pub fn cell_center(&self, row: usize, column: usize) -> (f64, f64) {
+        (
+            self.min_x + (column as f64 + 0.5) * self.cell_size,
+            self.min_y + (row as f64 + 0.5) * self.cell_size,
+        )
+    }
+
+    /// 验证网格参数的有效性
+    pub fn validate(&self) -> Result<()> {
+        let bounds = [self.min_x, self.min_y, self.max_x, self.max_y, self.receiver_height];
+        if bounds.iter().any(|value| !value.is_finite()) {
+            return Err(AppError::ValidationError(
+                "Grid bounds must be finite numbers".to_string(),
+            ));
+        }
+
+        if !self.cell_size.is_finite() || self.cell_size <= 0.0 {
+            return Err(AppError::ValidationError(
+                "Cell size must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.max_x <= self.min_x || self.max_y <= self.min_y {
+            return Err(AppError::ValidationError(
+                "Grid max bounds must be greater than min bounds".to_string(),
+            ));
+        }
+
+        let cells = self.columns().saturating_mul(self.rows());
+        if cells > MAX_GRID_CELLS {
+            return Err(AppError::ValidationError(format!(
+                "Grid has {} cells, at most {} are allowed",
+                cells, MAX_GRID_CELLS
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// 覆盖仿真参数
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoverageSimulation {
+    pub beacons: Vec<PlannedBeacon>,
+    pub grid: CoverageGrid,
+    pub model: PathLossModel,
+    /// 可接收的最低信号强度（dBm）
+    pub rssi_threshold: f64,
+    /// 单元被视为“可定位”所需的最少信标数
+    pub min_beacons: usize,
+}
+
+/// 覆盖仿真结果
+///
+/// 矩阵按行（Y方向）优先存储，`[row][column]`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CoverageMap {
+    pub rows: usize,
+    pub columns: usize,
+    /// 每个单元可接收的信标数
+    pub signal_counts: Vec<Vec<usize>>,
+    /// 每个单元的HDOP，几何不可解时为空
+    pub hdop: Vec<Vec<Option<f64>>>,
+    /// 达到 `min_beacons` 的单元占比
+    pub covered_ratio: f64,
+    /// 已覆盖单元的平均HDOP
+    pub mean_hdop: Option<f64>,
+}
+
+impl CoverageSimulation {
+    /// 验证仿真参数
+    pub fn validate(&self) -> Result<()> {
+        if self.beacons.is_empty() {
+            return Err(AppError::ValidationError(
+                "At least one beacon placement is required".to_string(),
+            ));
+        }
+
+        if !self.model.exponent.is_finite() || self.model.exponent <= 0.0 {
+            return Err(AppError::ValidationError(
+                "Path loss exponent must be greater than 0".to_string(),
+            ));
+        }
+
+        self.grid.validate()
+    }
+
+    /// 执行仿真
+    pub fn run(&self) -> Result<CoverageMap> {
+        self.run_with(|_| true)
+    }
+
+    /// 执行仿真，每算完一行网格以已完成比例调用 `on_row`
+    ///
+    /// `on_row` 返回 `false` 时停止计算并返回 `BusinessError`
+    pub fn run_with(&self, mut on_row: impl FnMut(f64) -> bool) -> Result<CoverageMap> {
+        self.validate()?;
+
+        let rows = self.grid.rows();
+        let columns = self.grid.columns();
+        let mut signal_counts = Vec::with_capacity(rows);
+        let mut hdop = Vec::with_capacity(rows);
+        let mut covered = 0;
+        let mut hdop_sum = 0.0;
+        let mut hdop_cells = 0;
+
+        for row in 0..rows {
+            let mut count_row = Vec::with_capacity(columns);
+            let mut hdop_row = Vec::with_capacity(columns);
+
+            for column in 0..columns {
+                let (x, y) = self.grid.cell_center(row, column);
+                let audible: Vec<&PlannedBeacon> = self
+                    .beacons
+                    .iter()
+                    .filter(|b| {
+                        let distance = distance_3d(b, x, y, self.grid.receiver_height);
+                        self.model.predict_rssi(b.power, distance) >= self.rssi_threshold
+                    })
+                    .collect();
+
+                let cell_hdop = horizontal_dop(&audible, x, y);
+                count_row.push(audible.len());
+                hdop_row.push(cell_hdop);
+
+                if audible.len() >= self.min_beacons {
+                    covered += 1;
+                    if let Some(value) = cell_hdop {
+                        hdop_sum += value;
+                        hdop_cells += 1;
+                    }
+                }
+            }
+
+            signal_counts.push(count_row);
+            hdop.push(hdop_row);
+
+            if !on_row((row + 1) as f64 / rows as f64) {
+                return Err(AppError::BusinessError("Coverage simulation cancelled".to_string()));
+            }
+        }
+
+        let mean_hdop = if hdop_cells > 0 {
+            Some(hdop_sum / hdop_cells as f64)
+        } else {
+            None
+        };
+
+        Ok(CoverageMap {
+            rows,
+            columns,
+            signal_counts,
+            hdop,
+            covered_ratio: covered as f64 / (rows * columns) as f64,
+            mean_hdop,
+        })
+    }
+}
+
+fn distance_3d(beacon: &PlannedBeacon, x: f64, y: f64, z: f64) -> f64 {
+    ((beacon.x - x).powi(2) + (beacon.y - y).powi(2) + (beacon.z - z).powi(2)).sqrt()
+}
+
+/// 计算水平精度因子
+///
+/// 以各信标到接收点的水平单位方向向量构成设计矩阵 H，
+/// `HDOP = sqrt(trace((HᵀH)⁻¹))`
+fn horizontal_dop(beacons: &[&PlannedBeacon], x: f64, y: f64) -> Option<f64> {
+    let (mut sxx, mut sxy, mut syy) = (0.0, 0.0, 0.0);
+
+    for beacon in beacons {
+        let dx = x - beacon.x;
+        let dy = y - beacon.y;
+        let range = (dx * dx + dy * dy).sqrt();
+        if range < 1e-9 {
+            // 正下方的信标不提供水平方向信息
+            continue;
+        }
+        let (ux, uy) = (dx / range, dy / range);
+        sxx += ux * ux;
+        sxy += ux * uy;
+        syy += uy * uy;
+    }
+
+    let det = sxx * syy - sxy * sxy;
+    if det < 1e-9 {
+        return None;
+    }
+
+    Some(((sxx + syy) / det).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn beacon(id: &str, x: f64, y: f64) -> PlannedBeacon {
+        PlannedBeacon { id: id.to_string(), x, y, z: 2.5, power: -59 }
+    }
+
+    fn square_simulation(threshold: f64) -> CoverageSimulation {
+        CoverageSimulation {
+            beacons: vec![
+                beacon("a", 0.0, 0.0),
+                beacon("b", 10.0, 0.0),
+                beacon("c", 10.0, 10.0),
+                beacon("d", 0.0, 10.0),
+            ],
+            grid: CoverageGrid {
+                min_x: 0.0,
+                min_y: 0.0,
+                max_x: 10.0,
+                max_y: 10.0,
+                cell_size: 1.0,
+                receiver_height: 1.2,
+            },
+            model: PathLossModel::default(),
+            rssi_threshold: threshold,
+            min_beacons: 3,
+        }
+    }
+
+    #[test]
+    fn test_planned_beacon_from_beacon() {
+        let location =
+            crate::domain::Location::new(1.0, 2.0, 3.0, "2F".to_string(), "area_009".to_string());
+        let beacon = Beacon::new(
+            "beacon_009".into(),
+            "FDA50693-A4E2-4FB1-AFCF-C6EB07647825".to_string(),
+            10000,
+            9,
+            location,
+            -65,
+            1000,
+            "active".to_string(),
+        );
+
+        let planned = PlannedBeacon::from(&beacon);
+        assert_eq!(
+            planned,
+            PlannedBeacon { id: "beacon_009".to_string(), x: 1.0, y: 2.0, z: 3.0, power: -65 }
+        );
+    }
+
+    #[test]
+    fn test_predict_rssi() {
+        let model = PathLossModel::default();
+        assert_eq!(model.predict_rssi(-59, 1.0), -59.0);
+        assert!((model.predict_rssi(-59, 10.0) - (-79.0)).abs() < 1e-9);
+        // 1米以内不超过参考功率
+        assert_eq!(model.predict_rssi(-59, 0.1), -59.0);
+    }
+
+    #[test]
+    fn test_full_coverage() {
+        let map = square_simulation(-100.0).run().unwrap();
+        assert_eq!((map.rows, map.columns), (10, 10));
+        assert!(map.signal_counts.iter().flatten().all(|&count| count == 4));
+        assert_eq!(map.covered_ratio, 1.0);
+
+        // 房间中心四个方向对称，HDOP = 1
+        let center = map.hdop[4][4].unwrap();
+        assert!(center > 0.99 && center < 1.2);
+    }
+
+    #[test]
+    fn test_partial_coverage() {
+        // 阈值约对应 5.6 米的接收半径
+        let map = square_simulation(-74.0).run().unwrap();
+        assert!(map.covered_ratio < 1.0);
+        assert!(map.signal_counts[0][0] >= 1);
+    }
+
+    #[test]
+    fn test_run_reports_progress_and_stops() {
+        let simulation = square_simulation(-100.0);
+        let mut reported = Vec::new();
+        simulation
+            .run_with(|done| {
+                reported.push(done);
+                true
+            })
+            .unwrap();
+        assert_eq!(reported.len(), 10);
+        assert_eq!(reported[0], 0.1);
+        assert_eq!(reported[9], 1.0);
+
+        let mut rows = 0;
+        let result = simulation.run_with(|_| {
+            rows += 1;
+            rows < 3
+        });
+        assert!(matches!(result, Err(AppError::BusinessError(_))));
+        assert_eq!(rows, 3);
+    }
+
+    #[test]
+    fn test_collinear_beacons_have_no_hdop() {
+        let mut simulation = square_simulation(-100.0);
+        simulation.beacons = vec![beacon("a", 0.0, 5.0), beacon("b", 10.0, 5.0)];
+        simulation.grid.min_y = 4.5;
+        simulation.grid.max_y = 5.5;
+        let map = simulation.run().unwrap();
+        assert!(map.hdop[0].iter().all(|value| value.is_none()));
+    }
+
+    #[test]
+    fn test_invalid_grid() {
+        let mut simulation = square_simulation(-100.0);
+        simulation.grid.cell_size = 0.0;
+        assert!(simulation.run().is_err());
+
+        let mut simulation = square_simulation(-100.0);
+        simulation.grid.max_x = 100_000.0;
+        assert!(simulation.run().is_err());
+
+        let mut simulation = square_simulation(-100.0);
+        simulation.beacons.clear();
+        assert!(simulation.run().is_err());
+    }
+}
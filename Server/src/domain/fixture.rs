@@ -0,0 +1,111 @@
+//! 灯具（安装点）分组与整体健康状态
+//!
+//! 一个灯具上可能安装多个信标。同一灯具上的信标全部失效时，更可能是灯具断电，
+//! 而不是各自的电池耗尽
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::domain::{Beacon, BeaconId};
+
+/// 灯具健康状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FixtureStatus {
+    /// 所有信标正常
+    Healthy,
+    /// 部分信标失效，应按单个信标故障处理
+    Degraded,
+    /// 多个信标全部失效，应按灯具级故障（如断电）处理
+    Outage,
+}
+
+/// 一个灯具的健康状况
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FixtureHealth {
+    pub fixture_id: String,
+    /// 安装在该灯具上的信标，按ID排序
+    pub beacon_ids: Vec<BeaconId>,
+    /// 其中失效（非活跃）的信标
+    pub failed_beacon_ids: Vec<BeaconId>,
+    pub status: FixtureStatus,
+}
+
+impl FixtureHealth {
+    /// 根据灯具上的信标评估健康状态
+    ///
+    /// 只有一个信标的灯具无法区分灯具故障与信标故障，失效时视为部分失效
+    pub fn evaluate(fixture_id: &str, beacons: &[&Beacon]) -> Self {
+        let mut beacon_ids: Vec<BeaconId> = beacons.iter().map(|b| b.id.clone()).collect();
+        beacon_ids.sort();
+        let mut failed_beacon_ids: Vec<BeaconId> = beacons
+            .iter()
+            .filter(|b| !b.is_active())
+            .map(|b| b.id.clone())
+            .collect();
+        failed_beacon_ids.sort();
+
+        let status = match failed_beacon_ids.len() {
+            0 => FixtureStatus::Healthy,
+            failed if failed == beacon_ids.len() && failed > 1 => FixtureStatus::Outage,
+            _ => FixtureStatus::Degraded,
+        };
+
+        Self {
+            fixture_id: fixture_id.to_string(),
+            beacon_ids,
+            failed_beacon_ids,
+            status,
+        }
+    }
+}
+
+/// 按灯具分组并评估健康状态，未指定灯具的信标不参与，结果按灯具ID排序
+pub fn fixture_health(beacons: &[Beacon]) -> Vec<FixtureHealth> {
+    let mut groups: BTreeMap<&str, Vec<&Beacon>> = BTreeMap::new();
+    for beacon in beacons {
+        if let Some(fixture_id) = &beacon.fixture_id {
+            groups.entry(fixture_id).or_default().push(beacon);
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(fixture_id, beacons)| FixtureHealth::evaluate(fixture_id, &beacons))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::BeaconBuilder;
+
+    #[test]
+    fn test_fixture_health() {
+        let beacons = vec![
+            BeaconBuilder::new("a1").fixture("fixture_a").inactive().build(),
+            BeaconBuilder::new("a2").fixture("fixture_a").inactive().build(),
+            BeaconBuilder::new("b1").fixture("fixture_b").inactive().build(),
+            BeaconBuilder::new("b2").fixture("fixture_b").build(),
+            BeaconBuilder::new("c1").fixture("fixture_c").build(),
+            BeaconBuilder::new("d1").fixture("fixture_d").inactive().build(),
+            BeaconBuilder::new("loose").inactive().build(),
+        ];
+
+        let health = fixture_health(&beacons);
+        let statuses: Vec<(&str, FixtureStatus)> = health
+            .iter()
+            .map(|h| (h.fixture_id.as_str(), h.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("fixture_a", FixtureStatus::Outage),
+                ("fixture_b", FixtureStatus::Degraded),
+                ("fixture_c", FixtureStatus::Healthy),
+                ("fixture_d", FixtureStatus::Degraded),
+            ]
+        );
+        assert_eq!(health[1].failed_beacon_ids, vec!["b1"]);
+    }
+}
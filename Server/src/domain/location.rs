@@ -45,6 +45,73 @@ impl Location {
     }
 }
 
+/// 某一时间段内有效的位置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LocationVersion {
+    pub location: Location,
+    /// 生效时间（Unix毫秒，含）
+    pub valid_from_ms: u64,
+    /// 失效时间（Unix毫秒，不含），为空表示当前仍有效
+    pub valid_to_ms: Option<u64>,
+}
+
+impl LocationVersion {
+    /// 判断该版本在指定时刻是否有效
+    pub fn is_valid_at(&self, at_ms: u64) -> bool {
+        at_ms >= self.valid_from_ms && self.valid_to_ms.is_none_or(|to| at_ms < to)
+    }
+}
+
+/// 位置变更历史
+///
+/// 按时间先后保存各版本，最多只有最后一个版本处于有效状态
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LocationHistory {
+    versions: Vec<LocationVersion>,
+}
+
+impl LocationHistory {
+    /// 记录自 `at_ms` 起生效的位置，位置未变化时不产生新版本
+    pub fn record(&mut self, location: Location, at_ms: u64) {
+        if let Some(current) = self.current() {
+            if current.location == location {
+                return;
+            }
+        }
+
+        self.close(at_ms);
+        self.versions.push(LocationVersion {
+            location,
+            valid_from_ms: at_ms,
+            valid_to_ms: None,
+        });
+    }
+
+    /// 在 `at_ms` 结束当前版本（例如设备被移除）
+    pub fn close(&mut self, at_ms: u64) {
+        if let Some(last) = self.versions.last_mut() {
+            if last.valid_to_ms.is_none() {
+                last.valid_to_ms = Some(at_ms.max(last.valid_from_ms));
+            }
+        }
+    }
+
+    /// 当前有效的版本
+    pub fn current(&self) -> Option<&LocationVersion> {
+        self.versions.last().filter(|v| v.valid_to_ms.is_none())
+    }
+
+    /// 指定时刻有效的版本
+    pub fn at(&self, at_ms: u64) -> Option<&LocationVersion> {
+        self.versions.iter().rev().find(|v| v.is_valid_at(at_ms))
+    }
+
+    /// 全部版本
+    pub fn versions(&self) -> &[LocationVersion] {
+        &self.versions
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,4 +132,27 @@ mod tests {
         let invalid_loc = Location::new(100.0, 200.0, 150.0, "".to_string(), "area_001".to_string());
         assert!(invalid_loc.validate().is_err());
     }
+
+    #[test]
+    fn test_location_history() {
+        let a = Location::new(1.0, 1.0, 2.5, "1F".to_string(), "area_001".to_string());
+        let b = Location::new(9.0, 1.0, 2.5, "1F".to_string(), "area_001".to_string());
+
+        let mut history = LocationHistory::default();
+        history.record(a.clone(), 100);
+        history.record(a.clone(), 150);
+        history.record(b.clone(), 200);
+        assert_eq!(history.versions().len(), 2);
+
+        assert_eq!(history.at(50), None);
+        assert_eq!(history.at(100).map(|v| &v.location), Some(&a));
+        assert_eq!(history.at(199).map(|v| &v.location), Some(&a));
+        assert_eq!(history.at(200).map(|v| &v.location), Some(&b));
+        assert_eq!(history.current().unwrap().location, b);
+
+        history.close(300);
+        assert!(history.current().is_none());
+        assert_eq!(history.at(300), None);
+        assert_eq!(history.at(250).map(|v| &v.location), Some(&b));
+    }
 }
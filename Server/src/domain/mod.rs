@@ -3,7 +3,11 @@
 //! 包含应用的核心业务模型，独立于技术实现
 
 pub mod beacon;
+pub mod coverage;
+pub mod fixture;
 pub mod location;
+pub mod template;
 
-pub use beacon::Beacon;
-pub use location::Location;
+pub use beacon::{Beacon, BeaconId};
+pub use location::{Location, LocationHistory, LocationVersion};
+pub use template::BeaconTemplate;
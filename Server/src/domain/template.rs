@@ -0,0 +1,81 @@
+//! Beacon 配置模板
+//!
+//! 同一场地的信标通常使用相同的默认配置（UUID、发射功率、广播间隔等），
+//! 创建信标时指定模板即可省去重复填写。
+//!
+//! 合并规则见 [`crate::api::dto::CreateBeaconRequest`]。
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AppError, Result};
+
+/// Beacon 配置模板，未设置的字段不参与合并
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct BeaconTemplate {
+    /// 模板ID，通过接口保存时取自路径
+    #[serde(default)]
+    pub id: String,
+    /// 模板说明
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// 发射功率（dBm）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub power: Option<i32>,
+    /// 广播间隔（毫秒）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+impl BeaconTemplate {
+    /// 验证模板中已设置的字段，取值范围与 [`Beacon::validate`] 一致
+    pub fn validate(&self) -> Result<()> {
+        if self.id.is_empty() {
+            return Err(AppError::ValidationError("Template ID cannot be empty".to_string()));
+        }
+
+        if self.uuid.as_ref().is_some_and(|uuid| uuid.is_empty()) {
+            return Err(AppError::ValidationError("UUID cannot be empty".to_string()));
+        }
+
+        if self.power.is_some_and(|power| !(-100..=0).contains(&power)) {
+            return Err(AppError::ValidationError(
+                "Power must be between -100 and 0 dBm".to_string(),
+            ));
+        }
+
+        if self.interval.is_some_and(|interval| interval <= 0) {
+            return Err(AppError::ValidationError(
+                "Interval must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template() -> BeaconTemplate {
+        BeaconTemplate {
+            id: "warehouse".to_string(),
+            uuid: Some("FDA50693-A4E2-4FB1-AFCF-C6EB07647825".to_string()),
+            power: Some(-65),
+            interval: Some(500),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_template_validation() {
+        assert!(template().validate().is_ok());
+        assert!(BeaconTemplate { id: String::new(), ..template() }.validate().is_err());
+        assert!(BeaconTemplate { power: Some(5), ..template() }.validate().is_err());
+        assert!(BeaconTemplate { interval: Some(0), ..template() }.validate().is_err());
+    }
+}
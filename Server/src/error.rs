@@ -9,18 +9,14 @@ use serde::Serialize;
 #[derive(Debug)]
 pub enum AppError {
     /// 业务逻辑错误
-    #[allow(dead_code)]
     BusinessError(String),
     /// 数据库错误
-    #[allow(dead_code)]
     DatabaseError(String),
     /// 验证错误
     ValidationError(String),
     /// 资源未找到
-    #[allow(dead_code)]
     NotFound(String),
     /// 内部服务器错误
-    #[allow(dead_code)]
     InternalError(String),
 }
 
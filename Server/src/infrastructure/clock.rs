@@ -0,0 +1,117 @@
+//! 时间来源
+//!
+//! 区分两种时间：墙上时间用于存储和展示的时间戳，可能因校时而跳变；
+//! 单调时间只用于计算时长，不受校时影响。两者不能混用相减。
+//! 测试中可注入 [`ManualClock`] 以得到确定的时间。
+
+#[cfg(any(test, feature = "testkit"))]
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// 时间来源
+pub trait Clock: Send + Sync {
+    /// 墙上时间（Unix毫秒）
+    fn now_ms(&self) -> u64;
+
+    /// 单调时间，即自时钟创建以来经过的时长
+    fn monotonic(&self) -> Duration;
+}
+
+/// 系统时钟
+pub struct SystemClock {
+    origin: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self { origin: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now_ms(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0)
+    }
+
+    fn monotonic(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// 手动控制的时钟，用于测试
+///
+/// 墙上时间与单调时间分别设置，可模拟校时导致的墙上时间回拨
+#[cfg(any(test, feature = "testkit"))]
+pub struct ManualClock {
+    wall_ms: AtomicU64,
+    elapsed_ms: AtomicU64,
+}
+
+#[cfg(any(test, feature = "testkit"))]
+impl ManualClock {
+    /// 以指定的墙上时间（Unix毫秒）创建
+    pub fn new(wall_ms: u64) -> Self {
+        Self {
+            wall_ms: AtomicU64::new(wall_ms),
+            elapsed_ms: AtomicU64::new(0),
+        }
+    }
+
+    /// 时间前进，墙上时间与单调时间同步增加
+    pub fn advance(&self, duration: Duration) {
+        let ms = duration.as_millis() as u64;
+        self.wall_ms.fetch_add(ms, Ordering::SeqCst);
+        self.elapsed_ms.fetch_add(ms, Ordering::SeqCst);
+    }
+
+    /// 只调整墙上时间，单调时间不变
+    pub fn set_wall_ms(&self, wall_ms: u64) {
+        self.wall_ms.store(wall_ms, Ordering::SeqCst);
+    }
+}
+
+#[cfg(any(test, feature = "testkit"))]
+impl Clock for ManualClock {
+    fn now_ms(&self) -> u64 {
+        self.wall_ms.load(Ordering::SeqCst)
+    }
+
+    fn monotonic(&self) -> Duration {
+        Duration::from_millis(self.elapsed_ms.load(Ordering::SeqCst))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock() {
+        let clock = ManualClock::new(1_000);
+        clock.advance(Duration::from_millis(500));
+        assert_eq!(clock.now_ms(), 1_500);
+        assert_eq!(clock.monotonic(), Duration::from_millis(500));
+
+        // 墙上时间回拨不影响单调时间
+        clock.set_wall_ms(900);
+        assert_eq!(clock.now_ms(), 900);
+        assert_eq!(clock.monotonic(), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_system_clock_is_monotonic() {
+        let clock = SystemClock::new();
+        let first = clock.monotonic();
+        assert!(clock.monotonic() >= first);
+        assert!(clock.now_ms() > 0);
+    }
+}
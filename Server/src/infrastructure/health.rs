@@ -0,0 +1,228 @@
+//! 服务自身运行状况跟踪
+//!
+//! 记录进程启动时间、累计启动次数（持久化）与最近的就绪状态变化
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use crate::infrastructure::clock::Clock;
+
+/// 保留的就绪状态变化记录数
+const MAX_TRANSITIONS: usize = 50;
+
+/// 持久化文件名
+const PROCESS_STATE_FILE: &str = "process_state.json";
+
+/// 就绪状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Readiness {
+    /// 启动中，尚未开始接受请求
+    Starting,
+    /// 可以处理请求
+    Ready,
+    /// 无法正常处理请求，例如配置了持久化但变更日志无法打开
+    NotReady,
+}
+
+/// 一次就绪状态变化
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadinessTransition {
+    pub from: Readiness,
+    pub to: Readiness,
+    /// 变化时间（Unix毫秒）
+    pub at_ms: u64,
+    pub reason: String,
+}
+
+/// 跨进程持久化的状态
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ProcessState {
+    start_count: u64,
+}
+
+/// 运行状况快照
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthInfo {
+    /// 本次启动时间（Unix毫秒）
+    pub started_at_ms: u64,
+    pub uptime_secs: u64,
+    /// 累计启动次数（含本次）
+    pub start_count: u64,
+    /// 重启次数，即 `start_count - 1`
+    pub restart_count: u64,
+    /// 启动次数是否已持久化
+    pub persisted: bool,
+    pub readiness: Readiness,
+    /// 最近的就绪状态变化，按时间先后排列
+    pub transitions: Vec<ReadinessTransition>,
+}
+
+struct ReadinessLog {
+    current: Readiness,
+    transitions: VecDeque<ReadinessTransition>,
+}
+
+/// 运行状况跟踪器
+pub struct HealthTracker {
+    clock: Arc<dyn Clock>,
+    started_at_ms: u64,
+    /// 启动时的单调时间，用于计算运行时长
+    started: Duration,
+    start_count: u64,
+    persisted: bool,
+    readiness: RwLock<ReadinessLog>,
+}
+
+impl HealthTracker {
+    /// 记录一次进程启动
+    ///
+    /// 指定 `data_dir` 时从中读取并递增累计启动次数；读写失败时退化为仅内存计数
+    pub fn start(data_dir: Option<&Path>, clock: Arc<dyn Clock>) -> Self {
+        let (start_count, persisted) = match data_dir {
+            Some(dir) => match Self::record_start(dir) {
+                Ok(count) => (count, true),
+                Err(e) => {
+                    tracing::warn!("Failed to persist process start count: {}", e);
+                    (1, false)
+                }
+            },
+            None => (1, false),
+        };
+
+        Self {
+            started_at_ms: clock.now_ms(),
+            started: clock.monotonic(),
+            clock,
+            start_count,
+            persisted,
+            readiness: RwLock::new(ReadinessLog {
+                current: Readiness::Starting,
+                transitions: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// 读取、递增并写回启动次数
+    fn record_start(dir: &Path) -> std::io::Result<u64> {
+        let path: PathBuf = dir.join(PROCESS_STATE_FILE);
+        let mut state = match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+                tracing::warn!("Ignoring corrupt {}: {}", path.display(), e);
+                ProcessState::default()
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => ProcessState::default(),
+            Err(e) => return Err(e),
+        };
+
+        state.start_count += 1;
+        std::fs::create_dir_all(dir)?;
+        std::fs::write(&path, serde_json::to_vec(&state)?)?;
+        Ok(state.start_count)
+    }
+
+    /// 更新就绪状态，状态未变化时不记录
+    pub async fn set_readiness(&self, readiness: Readiness, reason: &str) {
+        let mut log = self.readiness.write().await;
+        if log.current == readiness {
+            return;
+        }
+
+        tracing::info!("Readiness changed: {:?} -> {:?} ({})", log.current, readiness, reason);
+        let transition = ReadinessTransition {
+            from: log.current,
+            to: readiness,
+            at_ms: self.clock.now_ms(),
+            reason: reason.to_string(),
+        };
+        log.current = readiness;
+        if log.transitions.len() == MAX_TRANSITIONS {
+            log.transitions.pop_front();
+        }
+        log.transitions.push_back(transition);
+    }
+
+    /// 当前就绪状态
+    pub async fn readiness(&self) -> Readiness {
+        self.readiness.read().await.current
+    }
+
+    /// 运行状况快照
+    pub async fn info(&self) -> HealthInfo {
+        let log = self.readiness.read().await;
+        HealthInfo {
+            started_at_ms: self.started_at_ms,
+            uptime_secs: self.clock.monotonic().saturating_sub(self.started).as_secs(),
+            start_count: self.start_count,
+            restart_count: self.start_count.saturating_sub(1),
+            persisted: self.persisted,
+            readiness: log.current,
+            transitions: log.transitions.iter().cloned().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::{ManualClock, SystemClock};
+
+    #[tokio::test]
+    async fn test_readiness_transitions() {
+        let tracker = HealthTracker::start(None, Arc::new(SystemClock::new()));
+        assert_eq!(tracker.readiness().await, Readiness::Starting);
+
+        tracker.set_readiness(Readiness::Ready, "listener bound").await;
+        tracker.set_readiness(Readiness::Ready, "duplicate").await;
+        tracker.set_readiness(Readiness::NotReady, "maintenance").await;
+
+        let info = tracker.info().await;
+        assert_eq!(info.readiness, Readiness::NotReady);
+        assert_eq!(info.transitions.len(), 2);
+        assert_eq!(info.transitions[0].from, Readiness::Starting);
+        assert_eq!(info.transitions[1].reason, "maintenance");
+        assert_eq!((info.start_count, info.restart_count, info.persisted), (1, 0, false));
+    }
+
+    #[tokio::test]
+    async fn test_transition_history_is_bounded() {
+        let tracker = HealthTracker::start(None, Arc::new(SystemClock::new()));
+        for i in 0..MAX_TRANSITIONS + 10 {
+            let readiness = if i % 2 == 0 { Readiness::Ready } else { Readiness::NotReady };
+            tracker.set_readiness(readiness, "flap").await;
+        }
+        assert_eq!(tracker.info().await.transitions.len(), MAX_TRANSITIONS);
+    }
+
+    #[test]
+    fn test_start_count_persisted() {
+        let dir = std::env::temp_dir().join(format!("blnav-health-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(HealthTracker::start(Some(&dir), Arc::new(SystemClock::new())).start_count, 1);
+        let tracker = HealthTracker::start(Some(&dir), Arc::new(SystemClock::new()));
+        assert_eq!(tracker.start_count, 2);
+        assert!(tracker.persisted);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_uptime_ignores_wall_clock_jumps() {
+        let clock = Arc::new(ManualClock::new(1_000_000));
+        let tracker = HealthTracker::start(None, clock.clone());
+
+        clock.advance(Duration::from_secs(90));
+        clock.set_wall_ms(0);
+        tracker.set_readiness(Readiness::Ready, "listener bound").await;
+
+        let info = tracker.info().await;
+        assert_eq!(info.started_at_ms, 1_000_000);
+        assert_eq!(info.uptime_secs, 90);
+        assert_eq!(info.transitions[0].at_ms, 0);
+    }
+}
@@ -0,0 +1,362 @@
+//! 后台任务管理
+//!
+//! 覆盖仿真等耗时操作以任务形式在后台执行，客户端通过任务ID查询状态、进度和结果
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::task::AbortHandle;
+
+use crate::error::{AppError, Result};
+use crate::infrastructure::clock::{Clock, SystemClock};
+
+/// 保留的已结束任务数，超出后丢弃最早的
+const MAX_FINISHED_JOBS: usize = 100;
+
+/// 任务状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+impl JobStatus {
+    /// 任务是否已结束
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed | Self::Cancelled)
+    }
+}
+
+/// 任务信息
+#[derive(Debug, Clone, Serialize)]
+pub struct Job {
+    pub id: u64,
+    /// 任务类型，如 `coverage_simulation`
+    pub kind: String,
+    pub status: JobStatus,
+    /// 进度，0.0 ~ 1.0
+    pub progress: f64,
+    /// 失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 结果地址，仅成功的任务有
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_url: Option<String>,
+    /// 创建时间（Unix毫秒）
+    pub created_at_ms: u64,
+    /// 最近一次状态或进度变化时间（Unix毫秒）
+    pub updated_at_ms: u64,
+}
+
+struct JobRecord {
+    job: Job,
+    result: Option<Value>,
+    abort: Option<AbortHandle>,
+}
+
+#[derive(Default)]
+struct JobTable {
+    next_id: u64,
+    jobs: BTreeMap<u64, JobRecord>,
+}
+
+/// 任务管理器
+///
+/// 任务表使用同步锁，以便在阻塞线程中上报进度
+pub struct JobManager {
+    table: Arc<Mutex<JobTable>>,
+    clock: Arc<dyn Clock>,
+}
+
+/// 任务执行期间用于上报进度
+#[derive(Clone)]
+pub struct JobProgress {
+    id: u64,
+    table: Arc<Mutex<JobTable>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl JobManager {
+    pub fn new() -> Self {
+        Self {
+            table: Arc::default(),
+            clock: Arc::new(SystemClock::new()),
+        }
+    }
+
+    /// 使用指定的时钟记录任务时间
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 在后台执行任务，立即返回排队中的任务信息
+    ///
+    /// 任务成功时结果序列化为JSON保存，可通过 [`JobManager::result`] 获取
+    pub fn spawn<F, Fut, T>(&self, kind: &str, task: F) -> Job
+    where
+        F: FnOnce(JobProgress) -> Fut,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+        T: Serialize,
+    {
+        let job = {
+            let mut table = lock(&self.table);
+            table.next_id += 1;
+            let now = self.clock.now_ms();
+            let job = Job {
+                id: table.next_id,
+                kind: kind.to_string(),
+                status: JobStatus::Queued,
+                progress: 0.0,
+                error: None,
+                result_url: None,
+                created_at_ms: now,
+                updated_at_ms: now,
+            };
+            table.jobs.insert(job.id, JobRecord { job: job.clone(), result: None, abort: None });
+            job
+        };
+
+        let progress = JobProgress {
+            id: job.id,
+            table: Arc::clone(&self.table),
+            clock: Arc::clone(&self.clock),
+        };
+        let future = task(progress.clone());
+        let handle = tokio::spawn(async move {
+            progress.update(|record| record.job.status = JobStatus::Running);
+            let outcome = future.await.and_then(|value| {
+                serde_json::to_value(value)
+                    .map_err(|e| AppError::InternalError(format!("Failed to serialize job result: {}", e)))
+            });
+            progress.finish(outcome);
+        });
+
+        if let Some(record) = lock(&self.table).jobs.get_mut(&job.id) {
+            record.abort = Some(handle.abort_handle());
+        }
+        job
+    }
+
+    /// 获取任务信息
+    pub fn get(&self, id: u64) -> Result<Job> {
+        lock(&self.table)
+            .jobs
+            .get(&id)
+            .map(|record| record.job.clone())
+            .ok_or_else(|| not_found(id))
+    }
+
+    /// 列出任务，按创建先后排列，可按状态过滤
+    pub fn list(&self, status: Option<JobStatus>) -> Vec<Job> {
+        lock(&self.table)
+            .jobs
+            .values()
+            .filter(|record| status.is_none_or(|s| record.job.status == s))
+            .map(|record| record.job.clone())
+            .collect()
+    }
+
+    /// 获取已成功任务的结果
+    pub fn result(&self, id: u64) -> Result<Value> {
+        let table = lock(&self.table);
+        let record = table.jobs.get(&id).ok_or_else(|| not_found(id))?;
+        record.result.clone().ok_or_else(|| {
+            AppError::BusinessError(format!("Job {} has no result (status: {:?})", id, record.job.status))
+        })
+    }
+
+    /// 取消未结束的任务
+    ///
+    /// 已在阻塞线程中运行的计算无法中断，需通过 [`JobProgress::is_cancelled`] 自行停止，
+    /// 其结果会被丢弃
+    pub fn cancel(&self, id: u64) -> Result<Job> {
+        let mut table = lock(&self.table);
+        let record = table.jobs.get_mut(&id).ok_or_else(|| not_found(id))?;
+        if record.job.status.is_finished() {
+            return Err(AppError::BusinessError(format!(
+                "Job {} already finished (status: {:?})",
+                id, record.job.status
+            )));
+        }
+
+        if let Some(abort) = record.abort.take() {
+            abort.abort();
+        }
+        record.job.status = JobStatus::Cancelled;
+        record.job.updated_at_ms = self.clock.now_ms();
+        let job = record.job.clone();
+        prune(&mut table);
+        Ok(job)
+    }
+}
+
+impl Default for JobManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JobProgress {
+    /// 上报进度，超出 0.0 ~ 1.0 的值会被截断
+    pub fn set(&self, progress: f64) {
+        self.update(|record| record.job.progress = progress.clamp(0.0, 1.0));
+    }
+
+    /// 任务是否已被取消，长时间运行的任务应定期检查
+    pub fn is_cancelled(&self) -> bool {
+        lock(&self.table)
+            .jobs
+            .get(&self.id)
+            .is_none_or(|record| record.job.status == JobStatus::Cancelled)
+    }
+
+    fn update(&self, f: impl FnOnce(&mut JobRecord)) {
+        if let Some(record) = lock(&self.table).jobs.get_mut(&self.id) {
+            if !record.job.status.is_finished() {
+                f(record);
+                record.job.updated_at_ms = self.clock.now_ms();
+            }
+        }
+    }
+
+    fn finish(&self, outcome: Result<Value>) {
+        let id = self.id;
+        self.update(|record| {
+            record.abort = None;
+            match outcome {
+                Ok(value) => {
+                    record.job.status = JobStatus::Succeeded;
+                    record.job.progress = 1.0;
+                    record.job.result_url = Some(format!("/api/jobs/{}/result", id));
+                    record.result = Some(value);
+                }
+                Err(e) => {
+                    record.job.status = JobStatus::Failed;
+                    record.job.error = Some(e.to_string());
+                }
+            }
+        });
+        prune(&mut lock(&self.table));
+    }
+}
+
+/// 丢弃超出保留数量的最早的已结束任务
+fn prune(table: &mut JobTable) {
+    let finished: Vec<u64> = table
+        .jobs
+        .values()
+        .filter(|record| record.job.status.is_finished())
+        .map(|record| record.job.id)
+        .collect();
+
+    for id in finished.iter().take(finished.len().saturating_sub(MAX_FINISHED_JOBS)) {
+        table.jobs.remove(id);
+    }
+}
+
+fn lock(table: &Mutex<JobTable>) -> MutexGuard<'_, JobTable> {
+    // 持锁期间不会panic，锁中毒时数据仍然可用
+    table.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn not_found(id: u64) -> AppError {
+    AppError::NotFound(format!("Job with id {} not found", id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::ManualClock;
+    use std::time::Duration;
+
+    async fn wait_finished(manager: &JobManager, id: u64) -> Job {
+        for _ in 0..100 {
+            let job = manager.get(id).unwrap();
+            if job.status.is_finished() {
+                return job;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("job {} did not finish", id);
+    }
+
+    #[tokio::test]
+    async fn test_job_succeeds() {
+        let manager = JobManager::new();
+        let job = manager.spawn("test", |progress| async move {
+            progress.set(0.5);
+            Ok(42)
+        });
+        assert_eq!(job.status, JobStatus::Queued);
+
+        let job = wait_finished(&manager, job.id).await;
+        assert_eq!(job.status, JobStatus::Succeeded);
+        assert_eq!(job.progress, 1.0);
+        assert_eq!(job.result_url.as_deref(), Some("/api/jobs/1/result"));
+        assert_eq!(manager.result(job.id).unwrap(), 42);
+        assert!(manager.cancel(job.id).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_job_fails() {
+        let manager = JobManager::new();
+        let job = manager.spawn("test", |_| async {
+            Err::<(), _>(AppError::ValidationError("bad input".to_string()))
+        });
+
+        let job = wait_finished(&manager, job.id).await;
+        assert_eq!(job.status, JobStatus::Failed);
+        assert!(job.error.unwrap().contains("bad input"));
+        assert!(manager.result(job.id).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_job() {
+        let manager = JobManager::new();
+        let job = manager.spawn("test", |_| async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        });
+
+        let cancelled = manager.cancel(job.id).unwrap();
+        assert_eq!(cancelled.status, JobStatus::Cancelled);
+        assert_eq!(manager.list(Some(JobStatus::Cancelled)).len(), 1);
+        assert!(manager.list(Some(JobStatus::Running)).is_empty());
+        assert!(manager.get(999).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_job_timestamps_use_clock() {
+        let clock = Arc::new(ManualClock::new(5_000));
+        let manager = JobManager::new().with_clock(clock.clone());
+        let job = manager.spawn("test", |_| async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        });
+        assert_eq!(job.created_at_ms, 5_000);
+
+        clock.advance(Duration::from_secs(2));
+        let job = manager.cancel(job.id).unwrap();
+        assert_eq!(job.updated_at_ms, 7_000);
+    }
+
+    #[tokio::test]
+    async fn test_finished_jobs_are_pruned() {
+        let manager = JobManager::new();
+        for _ in 0..MAX_FINISHED_JOBS + 5 {
+            let job = manager.spawn("test", |_| async { Ok(()) });
+            manager.cancel(job.id).unwrap();
+        }
+
+        let jobs = manager.list(None);
+        assert_eq!(jobs.len(), MAX_FINISHED_JOBS);
+        assert_eq!(jobs[0].id, 6);
+    }
+}
@@ -0,0 +1,426 @@
+//! 配置变更日志
+//!
+//! 所有Beacon配置变更在生效前先追加写入日志（write-ahead），
+//! 可通过重放日志重建仓储数据，用于误删等情况下的灾难恢复
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::domain::{Beacon, BeaconId, LocationHistory};
+use crate::error::{AppError, Result};
+use crate::infrastructure::clock::{Clock, SystemClock};
+
+/// 日志文件名
+const JOURNAL_FILE: &str = "journal.ndjson";
+
+/// 一次配置变更
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Mutation {
+    CreateBeacon { beacon: Beacon },
+    UpdateBeacon { beacon: Beacon },
+    DeleteBeacon { id: BeaconId },
+    /// 将状态恢复到 `up_to_seq` 时的样子
+    Restore { up_to_seq: u64 },
+}
+
+/// 日志条目
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JournalEntry {
+    /// 单调递增的序号，从1开始
+    pub seq: u64,
+    /// 写入时间（Unix毫秒）
+    pub at_ms: u64,
+    pub mutation: Mutation,
+}
+
+/// 重放得到的状态
+#[derive(Debug, Default)]
+pub struct ReplayedState {
+    pub beacons: HashMap<BeaconId, Beacon>,
+    /// 按日志时间重建的位置历史，包括已删除的Beacon
+    pub locations: HashMap<BeaconId, LocationHistory>,
+    /// 已应用的条目数
+    pub applied: usize,
+    /// 因目标不存在而跳过的条目数
+    pub skipped: usize,
+}
+
+/// 重放结果摘要
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplaySummary {
+    pub applied: usize,
+    pub skipped: usize,
+    /// 重放后的Beacon数量
+    pub beacon_count: usize,
+    /// 重放截止的序号，为空表示重放全部条目
+    pub up_to_seq: Option<u64>,
+    /// 记录本次恢复的日志条目序号
+    pub restore_seq: Option<u64>,
+}
+
+struct JournalInner {
+    next_seq: u64,
+    /// 全部条目的内存副本，持久化时与日志文件保持一致
+    entries: Vec<JournalEntry>,
+}
+
+/// 追加写入的变更日志
+pub struct Journal {
+    path: Option<PathBuf>,
+    clock: Arc<dyn Clock>,
+    inner: Mutex<JournalInner>,
+}
+
+impl Journal {
+    /// 仅保存在内存中的日志
+    pub fn in_memory() -> Self {
+        Self {
+            path: None,
+            clock: Arc::new(SystemClock::new()),
+            inner: Mutex::new(JournalInner {
+                next_seq: 1,
+                entries: Vec::new(),
+            }),
+        }
+    }
+
+    /// 打开 `data_dir` 下的日志文件，不存在时在首次写入时创建
+    pub fn open(data_dir: &Path) -> Result<Self> {
+        let path = data_dir.join(JOURNAL_FILE);
+        let entries = read_entries(&path)?;
+        let next_seq = entries.last().map_or(1, |entry| entry.seq + 1);
+
+        Ok(Self {
+            path: Some(path),
+            clock: Arc::new(SystemClock::new()),
+            inner: Mutex::new(JournalInner { next_seq, entries }),
+        })
+    }
+
+    /// 是否写入日志文件
+    pub fn is_persistent(&self) -> bool {
+        self.path.is_some()
+    }
+
+    /// 使用指定的时钟记录条目时间
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 追加一条变更并返回写入的条目
+    pub async fn append(&self, mutation: Mutation) -> Result<JournalEntry> {
+        let mut inner = self.inner.lock().await;
+        let entry = JournalEntry {
+            seq: inner.next_seq,
+            at_ms: self.clock.now_ms(),
+            mutation,
+        };
+
+        // 持有锁等待写入完成，保证文件中的条目顺序与序号一致
+        if let Some(path) = &self.path {
+            let path = path.clone();
+            let written = entry.clone();
+            tokio::task::spawn_blocking(move || write_entry(&path, &written))
+                .await
+                .map_err(|e| AppError::InternalError(format!("Journal writer failed: {}", e)))??;
+        }
+
+        inner.entries.push(entry.clone());
+        inner.next_seq += 1;
+        Ok(entry)
+    }
+
+    /// 读取全部条目
+    pub async fn entries(&self) -> Result<Vec<JournalEntry>> {
+        Ok(self.inner.lock().await.entries.clone())
+    }
+}
+
+/// 从头重放日志，得到序号不超过 `up_to_seq` 时的状态
+///
+/// 重放是宽松的：更新不存在的Beacon视为创建，删除不存在的Beacon会被跳过。
+/// 恢复标记在同一遍顺序重放中解析：预先找出所有恢复点，重放经过时保存快照
+pub fn replay(entries: &[JournalEntry], up_to_seq: Option<u64>) -> ReplayedState {
+    replay_from(&HashMap::new(), entries, up_to_seq)
+}
+
+/// 以 `baseline` 为初始状态重放日志
+///
+/// 初始数据不写入日志，其位置视为一直有效；恢复到序号0即回到初始数据
+pub fn replay_from(
+    baseline: &HashMap<BeaconId, Beacon>,
+    entries: &[JournalEntry],
+    up_to_seq: Option<u64>,
+) -> ReplayedState {
+    let up_to_seq = up_to_seq.unwrap_or(u64::MAX);
+    let entries: Vec<&JournalEntry> = entries
+        .iter()
+        .take_while(|entry| entry.seq <= up_to_seq)
+        .collect();
+
+    let mut pending: BTreeSet<u64> = entries
+        .iter()
+        .filter_map(|entry| match entry.mutation {
+            Mutation::Restore { up_to_seq } => Some(up_to_seq.min(entry.seq - 1)),
+            _ => None,
+        })
+        .collect();
+    let mut snapshots: HashMap<u64, HashMap<BeaconId, Beacon>> = HashMap::new();
+    let mut state = ReplayedState {
+        beacons: baseline.clone(),
+        ..Default::default()
+    };
+    for beacon in baseline.values() {
+        state
+            .locations
+            .entry(beacon.id.clone())
+            .or_default()
+            .record(beacon.location.clone(), 0);
+    }
+
+    for entry in entries {
+        // 恢复点只会指向更早的条目，此时状态即为恢复点处的状态
+        while let Some(point) = pending.first().copied().filter(|point| *point < entry.seq) {
+            snapshots.insert(point, state.beacons.clone());
+            pending.pop_first();
+        }
+
+        match &entry.mutation {
+            Mutation::CreateBeacon { beacon } | Mutation::UpdateBeacon { beacon } => {
+                state
+                    .locations
+                    .entry(beacon.id.clone())
+                    .or_default()
+                    .record(beacon.location.clone(), entry.at_ms);
+                state.beacons.insert(beacon.id.clone(), beacon.clone());
+            }
+            Mutation::DeleteBeacon { id } => {
+                if state.beacons.remove(id).is_none() {
+                    state.skipped += 1;
+                    continue;
+                }
+                if let Some(history) = state.locations.get_mut(id) {
+                    history.close(entry.at_ms);
+                }
+            }
+            Mutation::Restore { up_to_seq } => {
+                let restored = snapshots
+                    .get(&(*up_to_seq).min(entry.seq - 1))
+                    .cloned()
+                    .unwrap_or_default();
+
+                // 位置历史按真实时间延续：恢复的Beacon自恢复时刻起回到旧位置
+                for (id, history) in state.locations.iter_mut() {
+                    if !restored.contains_key(id) {
+                        history.close(entry.at_ms);
+                    }
+                }
+                for beacon in restored.values() {
+                    state
+                        .locations
+                        .entry(beacon.id.clone())
+                        .or_default()
+                        .record(beacon.location.clone(), entry.at_ms);
+                }
+                state.beacons = restored;
+            }
+        }
+        state.applied += 1;
+    }
+
+    state
+}
+
+fn write_entry(path: &Path, entry: &JournalEntry) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(journal_error)?;
+    }
+
+    let mut line = serde_json::to_vec(entry)
+        .map_err(|e| AppError::InternalError(format!("Failed to encode journal entry: {}", e)))?;
+    line.push(b'\n');
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(journal_error)?;
+    file.write_all(&line).map_err(journal_error)?;
+    file.sync_data().map_err(journal_error)?;
+    Ok(())
+}
+
+fn read_entries(path: &Path) -> Result<Vec<JournalEntry>> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(journal_error(e)),
+    };
+
+    let mut entries = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str(line) {
+            Ok(entry) => entries.push(entry),
+            Err(e) => {
+                // 崩溃时最后一行可能只写了一半，忽略并停止读取
+                tracing::warn!("Stopping at unreadable journal line {}: {}", index + 1, e);
+                break;
+            }
+        }
+    }
+    Ok(entries)
+}
+
+fn journal_error(e: std::io::Error) -> AppError {
+    AppError::DatabaseError(format!("Journal I/O failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::BeaconBuilder;
+
+    fn entry(seq: u64, mutation: Mutation) -> JournalEntry {
+        JournalEntry { seq, at_ms: 0, mutation }
+    }
+
+    #[test]
+    fn test_replay() {
+        let entries = vec![
+            entry(1, Mutation::CreateBeacon { beacon: BeaconBuilder::new("a").build() }),
+            entry(2, Mutation::CreateBeacon { beacon: BeaconBuilder::new("b").build() }),
+            entry(3, Mutation::UpdateBeacon { beacon: BeaconBuilder::new("a").power(-70).build() }),
+            entry(4, Mutation::DeleteBeacon { id: "b".into() }),
+            entry(5, Mutation::DeleteBeacon { id: "missing".into() }),
+        ];
+
+        let state = replay(&entries, None);
+        assert_eq!(state.beacons.len(), 1);
+        assert_eq!(state.beacons["a"].power, -70);
+        assert_eq!((state.applied, state.skipped), (4, 1));
+
+        let state = replay(&entries, Some(2));
+        assert_eq!(state.beacons.len(), 2);
+        assert_eq!(state.beacons["a"].power, -59);
+    }
+
+    #[test]
+    fn test_replay_location_history() {
+        let entries = vec![
+            JournalEntry {
+                seq: 1,
+                at_ms: 100,
+                mutation: Mutation::CreateBeacon { beacon: BeaconBuilder::new("a").at(1.0, 1.0, 2.5).build() },
+            },
+            JournalEntry {
+                seq: 2,
+                at_ms: 200,
+                mutation: Mutation::UpdateBeacon { beacon: BeaconBuilder::new("a").at(5.0, 1.0, 2.5).build() },
+            },
+            JournalEntry {
+                seq: 3,
+                at_ms: 300,
+                mutation: Mutation::DeleteBeacon { id: "a".into() },
+            },
+        ];
+
+        let state = replay(&entries, None);
+        let history = &state.locations["a"];
+        assert_eq!(history.versions().len(), 2);
+        assert_eq!(history.at(150).unwrap().location.x, 1.0);
+        assert_eq!(history.at(250).unwrap().location.x, 5.0);
+        assert!(history.at(300).is_none());
+    }
+
+    #[test]
+    fn test_replay_restore_marker() {
+        let entries = vec![
+            entry(1, Mutation::CreateBeacon { beacon: BeaconBuilder::new("a").build() }),
+            entry(2, Mutation::DeleteBeacon { id: "a".into() }),
+            entry(3, Mutation::Restore { up_to_seq: 1 }),
+            entry(4, Mutation::CreateBeacon { beacon: BeaconBuilder::new("b").build() }),
+        ];
+
+        let state = replay(&entries, None);
+        assert!(state.beacons.contains_key("a"));
+        assert!(state.beacons.contains_key("b"));
+    }
+
+    #[test]
+    fn test_replay_from_baseline() {
+        let baseline = HashMap::from([("a".into(), BeaconBuilder::new("a").build())]);
+        let entries = vec![
+            JournalEntry {
+                seq: 1,
+                at_ms: 100,
+                mutation: Mutation::UpdateBeacon { beacon: BeaconBuilder::new("a").at(5.0, 1.0, 2.5).build() },
+            },
+            entry(2, Mutation::CreateBeacon { beacon: BeaconBuilder::new("b").build() }),
+            entry(3, Mutation::Restore { up_to_seq: 0 }),
+        ];
+
+        let state = replay_from(&baseline, &entries, Some(2));
+        assert_eq!(state.beacons.len(), 2);
+        let history = &state.locations["a"];
+        assert_eq!(history.versions().len(), 2);
+        assert_eq!(history.versions()[0].valid_from_ms, 0);
+        assert_eq!(history.at(150).unwrap().location.x, 5.0);
+
+        let state = replay_from(&baseline, &entries, None);
+        assert_eq!(state.beacons.len(), 1);
+        assert_eq!(state.beacons["a"], baseline["a"]);
+    }
+
+    #[test]
+    fn test_replay_many_restores() {
+        let mut entries = vec![entry(1, Mutation::CreateBeacon { beacon: BeaconBuilder::new("a").build() })];
+        for seq in 2..=200 {
+            let mutation = if seq % 2 == 0 {
+                Mutation::DeleteBeacon { id: "a".into() }
+            } else {
+                Mutation::Restore { up_to_seq: seq - 2 }
+            };
+            entries.push(entry(seq, mutation));
+        }
+        entries.push(entry(201, Mutation::Restore { up_to_seq: 2 }));
+
+        let state = replay(&entries, Some(199));
+        assert!(state.beacons.contains_key("a"));
+        assert_eq!((state.applied, state.skipped), (199, 0));
+
+        let state = replay(&entries, None);
+        assert!(state.beacons.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_file_journal_round_trip() {
+        let dir = std::env::temp_dir().join(format!("blnav-journal-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let journal = Journal::open(&dir).unwrap();
+        journal
+            .append(Mutation::CreateBeacon { beacon: BeaconBuilder::new("a").build() })
+            .await
+            .unwrap();
+        journal.append(Mutation::DeleteBeacon { id: "a".into() }).await.unwrap();
+
+        // 重新打开后序号继续递增
+        let reopened = Journal::open(&dir).unwrap();
+        let entry = reopened.append(Mutation::Restore { up_to_seq: 1 }).await.unwrap();
+        assert_eq!(entry.seq, 3);
+        assert_eq!(reopened.entries().await.unwrap().len(), 3);
+        assert_eq!(read_entries(&dir.join(JOURNAL_FILE)).unwrap().len(), 3);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,155 @@
+//! 信标标签二维码载荷
+//!
+//! 载荷包含信标身份信息，以 HMAC-SHA256 签名，安装人员扫码后可由服务端校验标签未被伪造或贴错
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::config::LabelConfig;
+use crate::domain::{Beacon, BeaconId};
+use crate::error::{AppError, Result};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 载荷格式版本
+const PAYLOAD_VERSION: u8 = 1;
+
+/// 二维码中编码的信标身份
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LabelPayload {
+    /// 载荷格式版本
+    pub v: u8,
+    pub beacon_id: BeaconId,
+    pub uuid: String,
+    pub major: i32,
+    pub minor: i32,
+    /// 签发时间（Unix毫秒）
+    pub issued_at_ms: u64,
+}
+
+/// 签名后的标签
+#[derive(Debug, Clone, Serialize)]
+pub struct SignedLabel {
+    pub payload: LabelPayload,
+    /// `<载荷>.<签名>`，两段均为无填充的 base64url
+    pub token: String,
+    /// 安装校验链接，即二维码内容
+    pub install_url: String,
+}
+
+/// 标签签名器
+pub struct LabelSigner {
+    key: Vec<u8>,
+    install_link_base: String,
+}
+
+impl LabelSigner {
+    /// 根据配置创建签名器，未配置签名密钥时返回错误
+    pub fn from_config(config: &LabelConfig) -> Result<Self> {
+        let key = config.signing_key.as_ref().ok_or_else(|| {
+            AppError::BusinessError("Label signing key is not configured (label.signing_key)".to_string())
+        })?;
+
+        Ok(Self {
+            key: key.as_bytes().to_vec(),
+            install_link_base: config.install_link_base.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// 为信标签发标签，`issued_at_ms` 为签发时间（Unix毫秒）
+    pub fn sign(&self, beacon: &Beacon, issued_at_ms: u64) -> Result<SignedLabel> {
+        let payload = LabelPayload {
+            v: PAYLOAD_VERSION,
+            beacon_id: beacon.id.clone(),
+            uuid: beacon.uuid.clone(),
+            major: beacon.major,
+            minor: beacon.minor,
+            issued_at_ms,
+        };
+
+        let json = serde_json::to_vec(&payload)
+            .map_err(|e| AppError::InternalError(format!("Failed to encode label payload: {}", e)))?;
+        let body = URL_SAFE_NO_PAD.encode(json);
+        let signature = URL_SAFE_NO_PAD.encode(self.mac(body.as_bytes()).finalize().into_bytes());
+        let token = format!("{}.{}", body, signature);
+
+        Ok(SignedLabel {
+            install_url: format!("{}?token={}", self.install_link_base, token),
+            payload,
+            token,
+        })
+    }
+
+    /// 校验标签并返回其中的载荷
+    pub fn verify(&self, token: &str) -> Result<LabelPayload> {
+        let invalid = || AppError::ValidationError("Invalid label token".to_string());
+
+        let (body, signature) = token.split_once('.').ok_or_else(invalid)?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).map_err(|_| invalid())?;
+        self.mac(body.as_bytes())
+            .verify_slice(&signature)
+            .map_err(|_| invalid())?;
+
+        let json = URL_SAFE_NO_PAD.decode(body).map_err(|_| invalid())?;
+        let payload: LabelPayload = serde_json::from_slice(&json).map_err(|_| invalid())?;
+        if payload.v != PAYLOAD_VERSION {
+            return Err(invalid());
+        }
+        Ok(payload)
+    }
+
+    fn mac(&self, data: &[u8]) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(data);
+        mac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::BeaconBuilder;
+
+    fn signer(key: &str) -> LabelSigner {
+        LabelSigner::from_config(&LabelConfig {
+            signing_key: Some(key.to_string()),
+            install_link_base: "https://example.com/install/".to_string(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let beacon = BeaconBuilder::new("beacon_100").major(2).minor(7).build();
+        let label = signer("secret").sign(&beacon, 1_000).unwrap();
+
+        assert!(label.install_url.starts_with("https://example.com/install?token="));
+        assert!(label.install_url.ends_with(&label.token));
+
+        let payload = signer("secret").verify(&label.token).unwrap();
+        assert_eq!(payload, label.payload);
+        assert_eq!(payload.beacon_id, "beacon_100");
+        assert_eq!(payload.minor, 7);
+        assert_eq!(payload.issued_at_ms, 1_000);
+    }
+
+    #[test]
+    fn test_reject_forged_token() {
+        let beacon = BeaconBuilder::new("beacon_100").build();
+        let label = signer("secret").sign(&beacon, 1_000).unwrap();
+        assert!(signer("other").verify(&label.token).is_err());
+
+        let other = signer("secret").sign(&BeaconBuilder::new("beacon_101").build(), 1_000).unwrap();
+        let (_, other_signature) = other.token.split_once('.').unwrap();
+        let (body, _) = label.token.split_once('.').unwrap();
+        assert!(signer("secret").verify(&format!("{}.{}", body, other_signature)).is_err());
+        assert!(signer("secret").verify("garbage").is_err());
+    }
+
+    #[test]
+    fn test_signing_key_required() {
+        assert!(LabelSigner::from_config(&LabelConfig::default()).is_err());
+    }
+}
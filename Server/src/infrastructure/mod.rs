@@ -2,7 +2,15 @@
 //!
 //! 包含持久化、状态管理等技术实现
 
+pub mod clock;
+pub mod health;
+pub mod jobs;
+pub mod journal;
+pub mod label;
+pub mod rejections;
 pub mod state;
 pub mod repository;
+pub mod seed;
+pub mod server;
 
 pub use state::AppState;
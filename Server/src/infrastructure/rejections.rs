@@ -0,0 +1,312 @@
+//! 被拒绝请求记录
+//!
+//! 供安全审计分析异常请求。只记录请求的元数据（来源、路径、查询参数名、请求体大小与顶层字段名），
+//! 不保存请求体内容。记录按条数和时长限制保留，配置了持久化目录时由后台线程同时写入文件，
+//! 不阻塞请求处理
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+
+use crate::config::RejectionsConfig;
+use crate::infrastructure::clock::Clock;
+
+/// 记录文件名
+const REJECTIONS_FILE: &str = "rejections.ndjson";
+
+/// 一次被拒绝的请求
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RejectedRequest {
+    /// 请求时间（Unix毫秒）
+    pub at_ms: u64,
+    pub method: String,
+    pub path: String,
+    /// 查询参数名，不含参数值
+    pub query_keys: Vec<String>,
+    /// 客户端地址（IP），未知时为空
+    pub source_ip: Option<String>,
+    pub user_agent: Option<String>,
+    /// 请求体大小（字节），取自 Content-Length，未声明时为实际读取的字节数
+    pub body_size: Option<u64>,
+    /// JSON请求体的顶层字段名
+    pub body_keys: Vec<String>,
+    pub status: u16,
+    /// 拒绝原因，取自错误响应的 `message`
+    pub reason: String,
+}
+
+/// 被拒绝请求的查询条件
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct RejectionFilter {
+    pub status: Option<u16>,
+    pub source_ip: Option<String>,
+}
+
+struct RejectionStore {
+    entries: VecDeque<RejectedRequest>,
+    /// 文件中的行数，超过保留条数的两倍时重写文件
+    file_lines: usize,
+}
+
+/// 交给后台线程执行的文件操作
+enum FileOp {
+    Append(RejectedRequest),
+    /// 用当前保留的记录重写文件
+    Rewrite(Vec<RejectedRequest>),
+}
+
+/// 后台写文件线程
+struct FileWriter {
+    sender: Sender<FileOp>,
+    handle: JoinHandle<()>,
+}
+
+/// 被拒绝请求记录
+pub struct RejectionLog {
+    max_entries: usize,
+    max_age_ms: u64,
+    clock: Arc<dyn Clock>,
+    store: Mutex<RejectionStore>,
+    /// 未配置持久化目录时为空
+    writer: Option<FileWriter>,
+}
+
+impl RejectionLog {
+    /// 创建记录，指定 `data_dir` 时从文件加载仍在保留期内的记录
+    pub fn open(config: &RejectionsConfig, data_dir: Option<&Path>, clock: Arc<dyn Clock>) -> Self {
+        let path = data_dir.map(|dir| dir.join(REJECTIONS_FILE));
+        let entries = match &path {
+            Some(path) => read_entries(path),
+            None => VecDeque::new(),
+        };
+
+        let log = Self {
+            max_entries: config.max_entries,
+            max_age_ms: config.max_age_secs.saturating_mul(1000),
+            clock,
+            store: Mutex::new(RejectionStore {
+                file_lines: entries.len(),
+                entries,
+            }),
+            writer: path.map(FileWriter::spawn),
+        };
+        log.prune(&mut log.lock());
+        log
+    }
+
+    /// 追加一条记录，写文件在后台进行，失败时只保留在内存中
+    pub fn record(&self, entry: RejectedRequest) {
+        let mut store = self.lock();
+
+        if let Some(writer) = &self.writer {
+            writer.send(FileOp::Append(entry.clone()));
+            store.file_lines += 1;
+        }
+
+        store.entries.push_back(entry);
+        self.prune(&mut store);
+    }
+
+    /// 按条件查询，按时间先后排列
+    pub fn query(&self, filter: &RejectionFilter) -> Vec<RejectedRequest> {
+        let mut store = self.lock();
+        self.prune(&mut store);
+
+        store
+            .entries
+            .iter()
+            .filter(|e| filter.status.is_none_or(|status| e.status == status))
+            .filter(|e| {
+                filter
+                    .source_ip
+                    .as_ref()
+                    .is_none_or(|ip| e.source_ip.as_ref() == Some(ip))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// 丢弃超出保留条数或保留时长的记录
+    fn prune(&self, store: &mut RejectionStore) {
+        let cutoff = self.clock.now_ms().saturating_sub(self.max_age_ms);
+        while store
+            .entries
+            .front()
+            .is_some_and(|e| e.at_ms < cutoff || store.entries.len() > self.max_entries)
+        {
+            store.entries.pop_front();
+        }
+
+        if let Some(writer) = &self.writer {
+            if store.file_lines > self.max_entries.max(1) * 2 {
+                writer.send(FileOp::Rewrite(store.entries.iter().cloned().collect()));
+                store.file_lines = store.entries.len();
+            }
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, RejectionStore> {
+        self.store.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for RejectionLog {
+    /// 等待后台线程写完已提交的记录
+    fn drop(&mut self) {
+        if let Some(FileWriter { sender, handle }) = self.writer.take() {
+            drop(sender);
+            let _ = handle.join();
+        }
+    }
+}
+
+impl FileWriter {
+    fn spawn(path: PathBuf) -> Self {
+        let (sender, receiver) = mpsc::channel::<FileOp>();
+        let handle = std::thread::Builder::new()
+            .name("rejections-writer".to_string())
+            .spawn(move || {
+                for op in receiver {
+                    let result = match &op {
+                        FileOp::Append(entry) => append_entry(&path, entry),
+                        FileOp::Rewrite(entries) => rewrite_entries(&path, entries),
+                    };
+                    if let Err(e) = result {
+                        tracing::warn!("Failed to persist rejected requests to {}: {}", path.display(), e);
+                    }
+                }
+            })
+            .expect("failed to spawn rejections writer thread");
+        Self { sender, handle }
+    }
+
+    fn send(&self, op: FileOp) {
+        // 线程只在析构时退出，发送不会失败
+        let _ = self.sender.send(op);
+    }
+}
+
+fn append_entry(path: &Path, entry: &RejectedRequest) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let mut line = serde_json::to_vec(entry)?;
+    line.push(b'\n');
+    OpenOptions::new().create(true).append(true).open(path)?.write_all(&line)
+}
+
+fn rewrite_entries(path: &Path, entries: &[RejectedRequest]) -> std::io::Result<()> {
+    let mut content = Vec::new();
+    for entry in entries {
+        serde_json::to_writer(&mut content, entry)?;
+        content.push(b'\n');
+    }
+
+    // 先写临时文件再替换，避免中途失败丢失记录
+    let tmp = path.with_extension("ndjson.tmp");
+    std::fs::write(&tmp, content)?;
+    std::fs::rename(&tmp, path)
+}
+
+/// 读取记录文件，忽略损坏的行
+fn read_entries(path: &Path) -> VecDeque<RejectedRequest> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                tracing::warn!("Failed to read {}: {}", path.display(), e);
+            }
+            return VecDeque::new();
+        }
+    };
+
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::clock::ManualClock;
+    use std::time::Duration;
+
+    fn rejected(at_ms: u64, status: u16) -> RejectedRequest {
+        RejectedRequest {
+            at_ms,
+            method: "POST".to_string(),
+            path: "/api/planning/coverage".to_string(),
+            query_keys: Vec::new(),
+            source_ip: Some("10.0.0.1".to_string()),
+            user_agent: None,
+            body_size: Some(12),
+            body_keys: vec!["grid".to_string()],
+            status,
+            reason: "invalid".to_string(),
+        }
+    }
+
+    fn config(max_entries: usize, max_age_secs: u64) -> RejectionsConfig {
+        RejectionsConfig { enabled: true, max_entries, max_age_secs }
+    }
+
+    #[test]
+    fn test_retention() {
+        let clock = Arc::new(ManualClock::new(100_000));
+        let log = RejectionLog::open(&config(3, 60), None, clock.clone());
+
+        for i in 0..5 {
+            log.record(rejected(100_000 + i, 400));
+        }
+        let entries = log.query(&RejectionFilter::default());
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].at_ms, 100_002);
+
+        clock.advance(Duration::from_secs(61));
+        assert!(log.query(&RejectionFilter::default()).is_empty());
+    }
+
+    #[test]
+    fn test_query_filter() {
+        let clock = Arc::new(ManualClock::new(100_000));
+        let log = RejectionLog::open(&config(10, 60), None, clock);
+        log.record(rejected(99_000, 400));
+        log.record(rejected(99_500, 404));
+
+        let filter = RejectionFilter { status: Some(404), ..Default::default() };
+        assert_eq!(log.query(&filter).len(), 1);
+
+        let filter = RejectionFilter { source_ip: Some("10.0.0.2".to_string()), ..Default::default() };
+        assert!(log.query(&filter).is_empty());
+    }
+
+    #[test]
+    fn test_persisted_and_compacted() {
+        let dir = std::env::temp_dir().join(format!("blnav-rejections-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let clock = Arc::new(ManualClock::new(100_000));
+
+        let log = RejectionLog::open(&config(2, 60), Some(&dir), clock.clone());
+        for i in 0..5 {
+            log.record(rejected(100_000 + i, 400));
+        }
+        drop(log);
+        let lines = std::fs::read_to_string(dir.join(REJECTIONS_FILE)).unwrap().lines().count();
+        assert!(lines <= 4);
+
+        let reopened = RejectionLog::open(&config(2, 60), Some(&dir), clock);
+        let entries = reopened.query(&RejectionFilter::default());
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].at_ms, 100_004);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
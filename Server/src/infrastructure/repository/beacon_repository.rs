@@ -2,132 +2,188 @@
 
 use tokio::sync::RwLock;
 use std::collections::HashMap;
-use crate::domain::Beacon;
-use crate::domain::Location;
+use std::sync::Arc;
+use crate::domain::{Beacon, BeaconId, LocationHistory};
 use crate::error::Result;
+use crate::infrastructure::journal::{self, Journal, Mutation, ReplaySummary};
+
+/// 仓储内部数据
+#[derive(Default)]
+struct BeaconStore {
+    beacons: HashMap<BeaconId, Beacon>,
+    /// 每个Beacon的位置历史，Beacon删除后仍保留
+    locations: HashMap<BeaconId, LocationHistory>,
+    /// 初始数据，重放日志时作为起点
+    baseline: HashMap<BeaconId, Beacon>,
+}
 
 /// Beacon 数据仓储
 ///
 /// 提供Beacon数据的持久化和查询能力
 pub struct BeaconRepository {
     /// 内存存储（生产环境应使用数据库）
-    data: RwLock<HashMap<String, Beacon>>,
+    data: RwLock<BeaconStore>,
+    /// 变更日志，所有变更生效前先写入
+    journal: Arc<Journal>,
 }
 
 impl BeaconRepository {
-    /// 创建新的仓储实例
+    /// 创建使用内存变更日志的仓储实例
     pub fn new() -> Self {
-        let mut data = HashMap::new();
-        
-        // 初始化默认数据
-        let beacons = vec![
-            Beacon::new(
-                "beacon_001".to_string(),
-                "FDA50693-A4E2-4FB1-AFCF-C6EB07647825".to_string(),
-                10000,
-                12345,
-                Location::new(100.0, 200.0, 150.0, "1F".to_string(), "area_001".to_string()),
-                -59,
-                1000,
-                "active".to_string(),
-            ),
-            Beacon::new(
-                "beacon_002".to_string(),
-                "FDA50693-A4E2-4FB1-AFCF-C6EB07647825".to_string(),
-                10000,
-                12346,
-                Location::new(500.0, 200.0, 150.0, "1F".to_string(), "area_001".to_string()),
-                -59,
-                1000,
-                "active".to_string(),
-            ),
-            Beacon::new(
-                "beacon_003".to_string(),
-                "FDA50693-A4E2-4FB1-AFCF-C6EB07647825".to_string(),
-                10000,
-                12347,
-                Location::new(300.0, 600.0, 150.0, "1F".to_string(), "area_002".to_string()),
-                -59,
-                1000,
-                "active".to_string(),
-            ),
-            Beacon::new(
-                "beacon_004".to_string(),
-                "FDA50693-A4E2-4FB1-AFCF-C6EB07647825".to_string(),
-                10000,
-                12348,
-                Location::new(100.0, 800.0, 150.0, "1F".to_string(), "area_002".to_string()),
-                -59,
-                1000,
-                "active".to_string(),
-            ),
-        ];
+        Self::with_journal(Arc::new(Journal::in_memory()))
+    }
 
-        for beacon in beacons {
-            data.insert(beacon.id.clone(), beacon);
+    /// 创建使用指定变更日志的仓储实例
+    pub fn with_journal(journal: Arc<Journal>) -> Self {
+        Self {
+            data: RwLock::new(BeaconStore::default()),
+            journal,
         }
+    }
 
-        Self {
-            data: RwLock::new(data),
+    /// 写入初始数据
+    ///
+    /// 初始数据来自配置（演示数据或数据文件），可在启动时重新生成，因此不写入变更日志，
+    /// 而是作为重放日志的起点保留。已存在的同ID数据会被覆盖。安装时间未知，位置视为一直有效。
+    pub async fn seed(&self, beacons: Vec<Beacon>) -> Result<usize> {
+        for beacon in &beacons {
+            beacon.validate()?;
+        }
+
+        let mut data = self.data.write().await;
+        let count = beacons.len();
+        for beacon in beacons {
+            data.locations
+                .entry(beacon.id.clone())
+                .or_default()
+                .record(beacon.location.clone(), 0);
+            data.baseline.insert(beacon.id.clone(), beacon.clone());
+            data.beacons.insert(beacon.id.clone(), beacon);
         }
+        Ok(count)
     }
 
     /// 获取所有Beacon
     pub async fn find_all(&self) -> Result<Vec<Beacon>> {
         let data = self.data.read().await;
-        Ok(data.values().cloned().collect())
+        Ok(data.beacons.values().cloned().collect())
     }
 
     /// 根据ID获取Beacon
-    #[allow(dead_code)]
-    pub async fn find_by_id(&self, id: &str) -> Result<Option<Beacon>> {
+    pub async fn find_by_id(&self, id: &BeaconId) -> Result<Option<Beacon>> {
         let data = self.data.read().await;
-        Ok(data.get(id).cloned())
+        Ok(data.beacons.get(id).cloned())
     }
 
-    /// 创建Beacon
-    #[allow(dead_code)]
+    /// 创建Beacon，同ID的Beacon已存在时返回错误
     pub async fn create(&self, beacon: Beacon) -> Result<Beacon> {
         beacon.validate()?;
         
         let mut data = self.data.write().await;
-        data.insert(beacon.id.clone(), beacon.clone());
+        if data.beacons.contains_key(&beacon.id) {
+            return Err(crate::error::AppError::BusinessError(
+                format!("Beacon with id {} already exists", beacon.id),
+            ));
+        }
+
+        let entry = self.journal
+            .append(Mutation::CreateBeacon { beacon: beacon.clone() })
+            .await?;
+        data.record_location(&beacon, entry.at_ms);
+        data.beacons.insert(beacon.id.clone(), beacon.clone());
         Ok(beacon)
     }
 
     /// 更新Beacon
-    #[allow(dead_code)]
     pub async fn update(&self, beacon: Beacon) -> Result<Beacon> {
         beacon.validate()?;
         
         let mut data = self.data.write().await;
-        if !data.contains_key(&beacon.id) {
+        if !data.beacons.contains_key(&beacon.id) {
             return Err(crate::error::AppError::NotFound(
                 format!("Beacon with id {} not found", beacon.id),
             ));
         }
         
-        data.insert(beacon.id.clone(), beacon.clone());
+        let entry = self.journal
+            .append(Mutation::UpdateBeacon { beacon: beacon.clone() })
+            .await?;
+        data.record_location(&beacon, entry.at_ms);
+        data.beacons.insert(beacon.id.clone(), beacon.clone());
         Ok(beacon)
     }
 
     /// 删除Beacon
-    #[allow(dead_code)]
-    pub async fn delete(&self, id: &str) -> Result<()> {
+    pub async fn delete(&self, id: &BeaconId) -> Result<()> {
         let mut data = self.data.write().await;
-        if data.remove(id).is_none() {
+        if !data.beacons.contains_key(id) {
             return Err(crate::error::AppError::NotFound(
                 format!("Beacon with id {} not found", id),
             ));
         }
+
+        let entry = self.journal
+            .append(Mutation::DeleteBeacon { id: id.clone() })
+            .await?;
+        if let Some(history) = data.locations.get_mut(id) {
+            history.close(entry.at_ms);
+        }
+        data.beacons.remove(id);
         Ok(())
     }
 
     /// 获取Beacon总数
-    #[allow(dead_code)]
     pub async fn count(&self) -> Result<usize> {
         let data = self.data.read().await;
-        Ok(data.len())
+        Ok(data.beacons.len())
+    }
+
+    /// 获取Beacon的位置历史，包括已删除的Beacon
+    pub async fn location_history(&self, id: &BeaconId) -> Result<LocationHistory> {
+        let data = self.data.read().await;
+        match data.locations.get(id) {
+            Some(history) => Ok(history.clone()),
+            None => Err(crate::error::AppError::NotFound(
+                format!("Beacon with id {} not found", id),
+            )),
+        }
+    }
+}
+
+impl BeaconStore {
+    fn record_location(&mut self, beacon: &Beacon, at_ms: u64) {
+        self.locations
+            .entry(beacon.id.clone())
+            .or_default()
+            .record(beacon.location.clone(), at_ms);
+    }
+}
+
+impl BeaconRepository {
+    /// 重放变更日志，用重放结果替换当前数据
+    ///
+    /// 重放从初始数据开始。指定 `up_to_seq` 时先在日志中追加恢复记录，再完整重放，
+    /// 得到该序号时的Beacon数据；位置历史按真实时间延续
+    pub async fn replay_journal(&self, up_to_seq: Option<u64>) -> Result<ReplaySummary> {
+        let mut data = self.data.write().await;
+
+        let restore_seq = match up_to_seq {
+            Some(up_to_seq) => Some(self.journal.append(Mutation::Restore { up_to_seq }).await?.seq),
+            None => None,
+        };
+
+        let entries = self.journal.entries().await?;
+        let replayed = journal::replay_from(&data.baseline, &entries, None);
+
+        data.beacons = replayed.beacons;
+        data.locations = replayed.locations;
+        Ok(ReplaySummary {
+            applied: replayed.applied,
+            skipped: replayed.skipped,
+            beacon_count: data.beacons.len(),
+            up_to_seq,
+            restore_seq,
+        })
     }
 }
 
@@ -140,25 +196,159 @@ impl Default for BeaconRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::infrastructure::clock::ManualClock;
+    use crate::infrastructure::seed;
+    use std::time::Duration;
+    use crate::testkit::BeaconBuilder;
+
+    async fn seeded_repo() -> BeaconRepository {
+        let repo = BeaconRepository::new();
+        repo.seed(seed::demo_beacons()).await.unwrap();
+        repo
+    }
 
     #[tokio::test]
-    async fn test_find_all() {
+    async fn test_new_repository_is_empty() {
         let repo = BeaconRepository::new();
+        assert_eq!(repo.count().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_find_all() {
+        let repo = seeded_repo().await;
         let beacons = repo.find_all().await.unwrap();
         assert_eq!(beacons.len(), 4);
     }
 
     #[tokio::test]
     async fn test_find_by_id() {
-        let repo = BeaconRepository::new();
-        let beacon = repo.find_by_id("beacon_001").await.unwrap();
+        let repo = seeded_repo().await;
+        let beacon = repo.find_by_id(&"beacon_001".into()).await.unwrap();
         assert!(beacon.is_some());
         assert_eq!(beacon.unwrap().id, "beacon_001");
     }
 
     #[tokio::test]
-    async fn test_count() {
+    async fn test_mutations_are_journaled() {
+        let journal = Arc::new(Journal::in_memory());
+        let repo = BeaconRepository::with_journal(Arc::clone(&journal));
+
+        repo.create(BeaconBuilder::new("beacon_100").build()).await.unwrap();
+        assert!(repo.create(BeaconBuilder::new("beacon_100").build()).await.is_err());
+        repo.update(BeaconBuilder::new("beacon_100").power(-70).build()).await.unwrap();
+        repo.delete(&"beacon_100".into()).await.unwrap();
+        assert!(repo.delete(&"beacon_100".into()).await.is_err());
+
+        let entries = journal.entries().await.unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[2].mutation, Mutation::DeleteBeacon { id: "beacon_100".into() });
+    }
+
+    #[tokio::test]
+    async fn test_replay_journal_restores_deleted_beacons() {
         let repo = BeaconRepository::new();
+        repo.create(BeaconBuilder::new("beacon_100").build()).await.unwrap();
+        repo.create(BeaconBuilder::new("beacon_101").build()).await.unwrap();
+
+        // 误删后恢复到删除前
+        repo.delete(&"beacon_100".into()).await.unwrap();
+        repo.delete(&"beacon_101".into()).await.unwrap();
+        let summary = repo.replay_journal(Some(2)).await.unwrap();
+        assert_eq!(summary.beacon_count, 2);
+        assert_eq!(summary.restore_seq, Some(5));
+        assert!(repo.find_by_id(&"beacon_100".into()).await.unwrap().is_some());
+
+        // 完整重放与恢复后的状态一致
+        let summary = repo.replay_journal(None).await.unwrap();
+        assert_eq!(summary.beacon_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_seed_is_not_journaled() {
+        let journal = Arc::new(Journal::in_memory());
+        let repo = BeaconRepository::with_journal(Arc::clone(&journal));
+        repo.seed(seed::demo_beacons()).await.unwrap();
+        assert!(journal.entries().await.unwrap().is_empty());
+
+        let mut invalid = seed::demo_beacons();
+        invalid[0].uuid.clear();
+        assert!(repo.seed(invalid).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_replay_journal_keeps_seeded_beacons() {
+        let repo = seeded_repo().await;
+        repo.update(BeaconBuilder::new("beacon_001").at(9.0, 1.0, 2.5).build()).await.unwrap();
+        repo.create(BeaconBuilder::new("beacon_100").build()).await.unwrap();
+
+        let summary = repo.replay_journal(None).await.unwrap();
+        assert_eq!(summary.beacon_count, 5);
+        assert_eq!((summary.applied, summary.skipped), (2, 0));
+
+        // 对初始数据的更新按更新重放，位置历史从初始位置延续
+        let history = repo.location_history(&"beacon_001".into()).await.unwrap();
+        let versions = history.versions();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].valid_from_ms, 0);
+        assert_eq!(versions[1].location.x, 9.0);
+
+        // 恢复到序号0即回到初始数据
+        let summary = repo.replay_journal(Some(0)).await.unwrap();
+        assert_eq!(summary.beacon_count, 4);
+        assert!(repo.find_by_id(&"beacon_100".into()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_location_versions() {
+        let repo = BeaconRepository::new();
+        repo.create(BeaconBuilder::new("beacon_100").at(1.0, 1.0, 2.5).build()).await.unwrap();
+        repo.update(BeaconBuilder::new("beacon_100").at(1.0, 1.0, 2.5).power(-65).build()).await.unwrap();
+        assert_eq!(repo.location_history(&"beacon_100".into()).await.unwrap().versions().len(), 1);
+
+        repo.update(BeaconBuilder::new("beacon_100").at(6.0, 1.0, 2.5).build()).await.unwrap();
+        let history = repo.location_history(&"beacon_100".into()).await.unwrap();
+        assert_eq!(history.current().unwrap().location.x, 6.0);
+
+        // 删除后历史仍可查询，但不再有当前位置
+        repo.delete(&"beacon_100".into()).await.unwrap();
+        let history = repo.location_history(&"beacon_100".into()).await.unwrap();
+        let versions = history.versions();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].valid_to_ms, Some(versions[1].valid_from_ms));
+        assert!(versions[1].valid_to_ms.is_some());
+        assert!(history.at(u64::MAX).is_none());
+
+        assert!(repo.location_history(&"missing".into()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_location_at_follows_journal_clock() {
+        let clock = Arc::new(ManualClock::new(1_000));
+        let journal = Arc::new(Journal::in_memory().with_clock(clock.clone()));
+        let repo = BeaconRepository::with_journal(journal);
+
+        repo.create(BeaconBuilder::new("beacon_100").at(1.0, 1.0, 2.5).build()).await.unwrap();
+        clock.advance(Duration::from_secs(60));
+        repo.update(BeaconBuilder::new("beacon_100").at(6.0, 1.0, 2.5).build()).await.unwrap();
+
+        let history = repo.location_history(&"beacon_100".into()).await.unwrap();
+        assert!(history.at(999).is_none());
+        assert_eq!(history.at(60_999).unwrap().location.x, 1.0);
+        assert_eq!(history.at(61_000).unwrap().location.x, 6.0);
+    }
+
+    #[tokio::test]
+    async fn test_seeded_locations_always_valid() {
+        let repo = seeded_repo().await;
+        let history = repo.location_history(&"beacon_001".into()).await.unwrap();
+        let version = history.at(0).unwrap();
+        assert_eq!(version.valid_from_ms, 0);
+        assert!(version.valid_to_ms.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_count() {
+        let repo = seeded_repo().await;
         let count = repo.count().await.unwrap();
         assert_eq!(count, 4);
     }
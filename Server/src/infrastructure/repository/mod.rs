@@ -1,5 +1,7 @@
 //! 仓储层模块
 
 pub mod beacon_repository;
+pub mod template_repository;
 
 pub use beacon_repository::BeaconRepository;
+pub use template_repository::TemplateRepository;
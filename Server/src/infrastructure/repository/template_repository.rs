@@ -0,0 +1,90 @@
+//! Beacon 配置模板仓储实现
+
+use std::collections::BTreeMap;
+use tokio::sync::RwLock;
+
+use crate::domain::BeaconTemplate;
+use crate::error::{AppError, Result};
+
+/// Beacon 配置模板仓储
+///
+/// 模板只影响之后创建的信标，不写入变更日志
+pub struct TemplateRepository {
+    templates: RwLock<BTreeMap<String, BeaconTemplate>>,
+}
+
+impl TemplateRepository {
+    pub fn new() -> Self {
+        Self {
+            templates: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    /// 获取所有模板，按ID排序
+    pub async fn find_all(&self) -> Result<Vec<BeaconTemplate>> {
+        Ok(self.templates.read().await.values().cloned().collect())
+    }
+
+    /// 根据ID获取模板
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<BeaconTemplate>> {
+        Ok(self.templates.read().await.get(id).cloned())
+    }
+
+    /// 保存模板，同ID模板已存在时整体替换
+    pub async fn save(&self, template: BeaconTemplate) -> Result<BeaconTemplate> {
+        template.validate()?;
+        self.templates
+            .write()
+            .await
+            .insert(template.id.clone(), template.clone());
+        Ok(template)
+    }
+
+    /// 删除模板，已用该模板创建的信标不受影响
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        self.templates
+            .write()
+            .await
+            .remove(id)
+            .map(|_| ())
+            .ok_or_else(|| AppError::NotFound(format!("Template with id {} not found", id)))
+    }
+}
+
+impl Default for TemplateRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(id: &str, power: i32) -> BeaconTemplate {
+        BeaconTemplate {
+            id: id.to_string(),
+            power: Some(power),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_save_replaces_and_delete() {
+        let repo = TemplateRepository::new();
+        repo.save(template("b", -59)).await.unwrap();
+        repo.save(template("a", -59)).await.unwrap();
+        repo.save(template("a", -70)).await.unwrap();
+
+        let templates = repo.find_all().await.unwrap();
+        assert_eq!(templates.len(), 2);
+        assert_eq!(templates[0].id, "a");
+        assert_eq!(templates[0].power, Some(-70));
+
+        assert!(repo.save(template("c", 10)).await.is_err());
+
+        repo.delete("a").await.unwrap();
+        assert!(repo.find_by_id("a").await.unwrap().is_none());
+        assert!(matches!(repo.delete("a").await, Err(AppError::NotFound(_))));
+    }
+}
@@ -0,0 +1,97 @@
+//! 初始数据加载
+//!
+//! 提供内置的演示数据，以及从JSON数据文件读取初始Beacon
+
+use std::path::Path;
+
+use crate::domain::{Beacon, Location};
+use crate::error::{AppError, Result};
+
+/// 内置演示数据：1F 两个区域共四个信标
+pub fn demo_beacons() -> Vec<Beacon> {
+    vec![
+        Beacon::new(
+            "beacon_001".into(),
+            "FDA50693-A4E2-4FB1-AFCF-C6EB07647825".to_string(),
+            10000,
+            12345,
+            Location::new(100.0, 200.0, 150.0, "1F".to_string(), "area_001".to_string()),
+            -59,
+            1000,
+            "active".to_string(),
+        ),
+        Beacon::new(
+            "beacon_002".into(),
+            "FDA50693-A4E2-4FB1-AFCF-C6EB07647825".to_string(),
+            10000,
+            12346,
+            Location::new(500.0, 200.0, 150.0, "1F".to_string(), "area_001".to_string()),
+            -59,
+            1000,
+            "active".to_string(),
+        ),
+        Beacon::new(
+            "beacon_003".into(),
+            "FDA50693-A4E2-4FB1-AFCF-C6EB07647825".to_string(),
+            10000,
+            12347,
+            Location::new(300.0, 600.0, 150.0, "1F".to_string(), "area_002".to_string()),
+            -59,
+            1000,
+            "active".to_string(),
+        ),
+        Beacon::new(
+            "beacon_004".into(),
+            "FDA50693-A4E2-4FB1-AFCF-C6EB07647825".to_string(),
+            10000,
+            12348,
+            Location::new(100.0, 800.0, 150.0, "1F".to_string(), "area_002".to_string()),
+            -59,
+            1000,
+            "active".to_string(),
+        ),
+    ]
+}
+
+/// 从数据文件读取Beacon
+///
+/// 文件内容为Beacon对象的JSON数组，字段与 `GET /api/all_beacons` 返回的一致
+pub fn load_fixture(path: &Path) -> Result<Vec<Beacon>> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        AppError::ValidationError(format!("Failed to read fixture {}: {}", path.display(), e))
+    })?;
+    parse_fixture(&content)
+        .map_err(|e| AppError::ValidationError(format!("Invalid fixture {}: {}", path.display(), e)))
+}
+
+fn parse_fixture(content: &str) -> std::result::Result<Vec<Beacon>, String> {
+    let beacons: Vec<Beacon> = serde_json::from_str(content).map_err(|e| e.to_string())?;
+    for beacon in &beacons {
+        beacon.validate().map_err(|e| format!("beacon {}: {}", beacon.id, e))?;
+    }
+    Ok(beacons)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_demo_beacons_are_valid() {
+        let beacons = demo_beacons();
+        assert_eq!(beacons.len(), 4);
+        assert!(beacons.iter().all(|b| b.validate().is_ok()));
+    }
+
+    #[test]
+    fn test_parse_fixture() {
+        let content = serde_json::to_string(&demo_beacons()[..2]).unwrap();
+        let beacons = parse_fixture(&content).unwrap();
+        assert_eq!(beacons.len(), 2);
+        assert_eq!(beacons[1].id, "beacon_002");
+
+        assert!(parse_fixture("{}").is_err());
+        let invalid = content.replace("\"power\":-59", "\"power\":10");
+        assert!(parse_fixture(&invalid).is_err());
+    }
+}
@@ -0,0 +1,172 @@
+//! HTTP 服务器
+//!
+//! 负责监听连接，并按照配置对HTTP/1.1连接保持与HTTP/2参数进行调优
+
+use axum::extract::ConnectInfo;
+use axum::{Extension, Router};
+use hyper_util::rt::{TokioExecutor, TokioIo, TokioTimer};
+use hyper_util::server::conn::auto::Builder;
+use hyper_util::service::TowerToHyperService;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tower::Layer;
+
+use crate::config::ServerConfig;
+
+/// 启动服务器，持续接受连接直至进程退出
+pub async fn serve(listener: TcpListener, app: Router, config: &ServerConfig) -> std::io::Result<()> {
+    let builder = connection_builder(config);
+
+    loop {
+        let (stream, remote_addr) = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                // 文件描述符耗尽等错误时稍作等待，避免空转
+                tracing::warn!("Failed to accept connection: {}", e);
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                continue;
+            }
+        };
+
+        let builder = builder.clone();
+        // 与 axum::serve 的 into_make_service_with_connect_info 一样提供客户端地址
+        let service = TowerToHyperService::new(Extension(ConnectInfo(remote_addr)).layer(app.clone()));
+
+        tokio::spawn(async move {
+            if let Err(e) = builder.serve_connection(TokioIo::new(stream), service).await {
+                tracing::debug!("Connection from {} closed with error: {}", remote_addr, e);
+            }
+        });
+    }
+}
+
+/// 根据配置构建连接处理器
+fn connection_builder(config: &ServerConfig) -> Builder<TokioExecutor> {
+    let mut builder = Builder::new(TokioExecutor::new());
+
+    // HTTP/1.1 的头部读取超时从等待下一个请求时开始计时，因此同时充当空闲连接超时
+    builder
+        .http1()
+        .timer(TokioTimer::new())
+        .keep_alive(config.keep_alive)
+        .header_read_timeout(config.keep_alive_timeout());
+
+    builder
+        .http2()
+        .timer(TokioTimer::new())
+        .max_concurrent_streams(config.http2_max_concurrent_streams)
+        .keep_alive_interval(config.http2_keep_alive_interval());
+
+    if config.http2_enabled {
+        builder
+    } else {
+        builder.http1_only()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::TestServer;
+    use http_body_util::{BodyExt, Empty};
+    use hyper::body::Bytes;
+    use hyper::{header::HOST, Request, StatusCode};
+    use tokio::net::TcpStream;
+
+    fn health_request(uri: String) -> Request<Empty<Bytes>> {
+        Request::get(uri)
+            .header(HOST, "localhost")
+            .body(Empty::new())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_http1_connection_reuse() {
+        let server = TestServer::spawn().await;
+        let addr = server.addr();
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let (mut sender, conn) = hyper::client::conn::http1::handshake(TokioIo::new(stream))
+            .await
+            .unwrap();
+        tokio::spawn(conn);
+
+        // 同一个TCP连接上连续发送多个请求
+        for _ in 0..3 {
+            sender.ready().await.unwrap();
+            let res = sender.send_request(health_request("/health".to_string())).await.unwrap();
+            assert_eq!(res.status(), StatusCode::OK);
+            res.into_body().collect().await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_http1_keep_alive_disabled() {
+        let config = ServerConfig {
+            keep_alive: false,
+            ..ServerConfig::default()
+        };
+        let server = TestServer::spawn_with_config(config).await;
+        let addr = server.addr();
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let (mut sender, conn) = hyper::client::conn::http1::handshake(TokioIo::new(stream))
+            .await
+            .unwrap();
+        tokio::spawn(conn);
+
+        let res = sender.send_request(health_request("/health".to_string())).await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        res.into_body().collect().await.unwrap();
+
+        // 服务器应在响应后关闭连接
+        assert!(sender.ready().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_http2_multiplexing() {
+        let server = TestServer::spawn().await;
+        let addr = server.addr();
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let (sender, conn) =
+            hyper::client::conn::http2::handshake(TokioExecutor::new(), TokioIo::new(stream))
+                .await
+                .unwrap();
+        tokio::spawn(conn);
+
+        let mut handles = Vec::new();
+        for _ in 0..5 {
+            let mut sender = sender.clone();
+            let uri = format!("http://{}/health", addr);
+            handles.push(tokio::spawn(async move {
+                sender.send_request(health_request(uri)).await.unwrap().status()
+            }));
+        }
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap(), StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_http2_disabled() {
+        let config = ServerConfig {
+            http2_enabled: false,
+            ..ServerConfig::default()
+        };
+        let server = TestServer::spawn_with_config(config).await;
+        let addr = server.addr();
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let uri = format!("http://{}/health", addr);
+
+        // 握手或请求阶段任意一步失败都说明服务器拒绝了HTTP/2
+        let handshake =
+            hyper::client::conn::http2::handshake(TokioExecutor::new(), TokioIo::new(stream)).await;
+        let result = match handshake {
+            Ok((mut sender, conn)) => {
+                tokio::spawn(conn);
+                sender.send_request(health_request(uri)).await.map(|_| ())
+            }
+            Err(e) => Err(e),
+        };
+        assert!(result.is_err());
+    }
+}
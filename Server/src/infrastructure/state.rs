@@ -1,25 +1,101 @@
 //! 应用状态管理
 
 use std::sync::Arc;
-use crate::infrastructure::repository::BeaconRepository;
+use crate::application::{AdminService, BeaconService, PlanningService, TemplateService};
+use crate::config::AppConfig;
+use crate::error::Result;
+use crate::infrastructure::clock::{Clock, SystemClock};
+use crate::infrastructure::health::{HealthTracker, Readiness};
+use crate::infrastructure::jobs::JobManager;
+use crate::infrastructure::journal::{Journal, ReplaySummary};
+use crate::infrastructure::rejections::RejectionLog;
+use crate::infrastructure::repository::{BeaconRepository, TemplateRepository};
+use crate::infrastructure::seed;
 
 /// 应用全局状态
 ///
 /// 管理应用的所有共享状态，包括Beacon数据等
 pub struct AppState {
+    /// 时间来源
+    clock: Arc<dyn Clock>,
+    /// 生效中的应用配置
+    config: Arc<AppConfig>,
+    /// 服务运行状况
+    health: Arc<HealthTracker>,
+    /// 配置变更日志
+    journal: Arc<Journal>,
     /// Beacon 仓储
     beacon_repo: Arc<BeaconRepository>,
+    /// Beacon 配置模板
+    templates: Arc<TemplateRepository>,
+    /// 后台任务
+    jobs: Arc<JobManager>,
+    /// 被拒绝请求记录，未启用时为空
+    rejections: Arc<RejectionLog>,
 }
 
 impl AppState {
-    /// 创建新的应用状态
+    /// 以默认配置创建新的应用状态
     pub fn new() -> Self {
-        let beacon_repo = Arc::new(BeaconRepository::new());
-        
-        // 初始化默认数据
-        Self::init_default_data(&beacon_repo);
-        
-        Self { beacon_repo }
+        Self::with_config(AppConfig::default())
+    }
+
+    /// 以指定配置创建应用状态
+    pub fn with_config(config: AppConfig) -> Self {
+        Self::with_clock(config, Arc::new(SystemClock::new()))
+    }
+
+    /// 以指定配置和时钟创建应用状态，测试中可注入手动时钟
+    pub fn with_clock(config: AppConfig, clock: Arc<dyn Clock>) -> Self {
+        let journal = Arc::new(Self::open_journal(&config).with_clock(Arc::clone(&clock)));
+        let beacon_repo = Arc::new(BeaconRepository::with_journal(Arc::clone(&journal)));
+        let health = Arc::new(HealthTracker::start(
+            config.storage.data_dir.as_deref(),
+            Arc::clone(&clock),
+        ));
+        let jobs = Arc::new(JobManager::new().with_clock(Arc::clone(&clock)));
+        let rejections = Arc::new(RejectionLog::open(
+            &config.rejections,
+            config.storage.data_dir.as_deref(),
+            Arc::clone(&clock),
+        ));
+
+        Self {
+            clock,
+            config: Arc::new(config),
+            health,
+            journal,
+            beacon_repo,
+            templates: Arc::new(TemplateRepository::new()),
+            jobs,
+            rejections,
+        }
+    }
+
+    /// 打开变更日志，未配置持久化目录时使用内存日志
+    fn open_journal(config: &AppConfig) -> Journal {
+        match &config.storage.data_dir {
+            Some(dir) => Journal::open(dir).unwrap_or_else(|e| {
+                tracing::error!("Failed to open journal, falling back to memory: {}", e);
+                Journal::in_memory()
+            }),
+            None => Journal::in_memory(),
+        }
+    }
+
+    /// 获取时间来源
+    pub fn clock(&self) -> Arc<dyn Clock> {
+        Arc::clone(&self.clock)
+    }
+
+    /// 获取应用配置
+    pub fn config(&self) -> Arc<AppConfig> {
+        Arc::clone(&self.config)
+    }
+
+    /// 获取运行状况跟踪器
+    pub fn health(&self) -> Arc<HealthTracker> {
+        Arc::clone(&self.health)
     }
 
     /// 获取Beacon仓储
@@ -27,10 +103,77 @@ impl AppState {
         Arc::clone(&self.beacon_repo)
     }
 
-    /// 初始化默认数据
-    fn init_default_data(_repo: &BeaconRepository) {
-        // 通过将init_default_data改为async方式处理
-        // 这里仅占位，实际初始化通过异步方式在启动时进行
+    /// 获取后台任务管理器
+    pub fn jobs(&self) -> Arc<JobManager> {
+        Arc::clone(&self.jobs)
+    }
+
+    /// 获取Beacon应用服务
+    pub fn beacon_service(&self) -> BeaconService<BeaconRepository> {
+        BeaconService::new(Arc::clone(&self.beacon_repo), Arc::clone(&self.clock))
+    }
+
+    /// 获取Beacon配置模板应用服务
+    pub fn template_service(&self) -> TemplateService {
+        TemplateService::new(Arc::clone(&self.templates))
+    }
+
+    /// 获取管理应用服务
+    pub fn admin_service(&self) -> AdminService {
+        AdminService::new(
+            Arc::clone(&self.config),
+            Arc::clone(&self.journal),
+            Arc::clone(&self.beacon_repo),
+            Arc::clone(&self.rejections),
+        )
+    }
+
+    /// 获取部署规划应用服务
+    pub fn planning_service(&self) -> PlanningService {
+        PlanningService::new(Arc::clone(&self.jobs))
+    }
+
+    /// 获取被拒绝请求记录
+    pub fn rejections(&self) -> Arc<RejectionLog> {
+        Arc::clone(&self.rejections)
+    }
+
+    /// 启动完成后更新就绪状态
+    ///
+    /// 配置了持久化目录但变更日志退化为内存日志时，变更会在重启后丢失，标记为未就绪
+    pub async fn mark_ready(&self, reason: &str) {
+        if self.config.storage.data_dir.is_some() && !self.journal.is_persistent() {
+            self.health
+                .set_readiness(Readiness::NotReady, "journal unavailable, changes are not persisted")
+                .await;
+        } else {
+            self.health.set_readiness(Readiness::Ready, reason).await;
+        }
+    }
+
+    /// 按配置写入初始数据，返回写入的Beacon数量
+    ///
+    /// 演示数据与数据文件可同时启用，数据文件中的同ID条目优先
+    pub async fn seed_data(&self) -> Result<usize> {
+        let mut beacons = Vec::new();
+        if self.config.seed.demo_data {
+            beacons.extend(seed::demo_beacons());
+        }
+        if let Some(path) = &self.config.seed.fixture_file {
+            beacons.extend(seed::load_fixture(path)?);
+        }
+
+        self.beacon_repo.seed(beacons).await
+    }
+
+    /// 用持久化的变更日志重建Beacon数据，应在写入初始数据之后调用
+    ///
+    /// 内存日志或空日志无需重放，返回 `None`
+    pub async fn restore_journal(&self) -> Result<Option<ReplaySummary>> {
+        if !self.journal.is_persistent() || self.journal.entries().await?.is_empty() {
+            return Ok(None);
+        }
+        self.beacon_repo.replay_journal(None).await.map(Some)
     }
 }
 
@@ -39,3 +182,81 @@ impl Default for AppState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::BeaconBuilder;
+
+    #[tokio::test]
+    async fn test_seed_data() {
+        let state = AppState::new();
+        assert_eq!(state.seed_data().await.unwrap(), 0);
+
+        let mut config = AppConfig::default();
+        config.seed.demo_data = true;
+        let state = AppState::with_config(config);
+        assert_eq!(state.seed_data().await.unwrap(), 4);
+        assert_eq!(state.beacon_repository().count().await.unwrap(), 4);
+
+        let mut config = AppConfig::default();
+        config.seed.fixture_file = Some("/nonexistent/fixture.json".into());
+        assert!(AppState::with_config(config).seed_data().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_not_ready_without_journal() {
+        let state = AppState::new();
+        state.mark_ready("listener bound").await;
+        assert_eq!(state.health().readiness().await, Readiness::Ready);
+
+        // 持久化目录实际是文件，变更日志无法打开
+        let file = std::env::temp_dir().join(format!("blnav-not-a-dir-{}", std::process::id()));
+        std::fs::write(&file, b"").unwrap();
+        let mut config = AppConfig::default();
+        config.storage.data_dir = Some(file.clone());
+        let state = AppState::with_config(config);
+        state.mark_ready("listener bound").await;
+
+        let info = state.health().info().await;
+        assert_eq!(info.readiness, Readiness::NotReady);
+        assert_eq!(info.transitions[0].reason, "journal unavailable, changes are not persisted");
+
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_restore_journal_after_restart() {
+        let dir = std::env::temp_dir().join(format!("blnav-state-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let config = || {
+            let mut config = AppConfig::default();
+            config.seed.demo_data = true;
+            config.storage.data_dir = Some(dir.clone());
+            config
+        };
+
+        let state = AppState::with_config(config());
+        state.seed_data().await.unwrap();
+        assert!(state.restore_journal().await.unwrap().is_none());
+        let repo = state.beacon_repository();
+        repo.create(BeaconBuilder::new("beacon_100").build()).await.unwrap();
+        repo.update(BeaconBuilder::new("beacon_001").power(-70).build()).await.unwrap();
+        repo.delete(&"beacon_002".into()).await.unwrap();
+        drop(state);
+
+        let restarted = AppState::with_config(config());
+        restarted.seed_data().await.unwrap();
+        let summary = restarted.restore_journal().await.unwrap().unwrap();
+        assert_eq!(summary.applied, 3);
+
+        let repo = restarted.beacon_repository();
+        assert_eq!(repo.count().await.unwrap(), 4);
+        assert_eq!(repo.find_by_id(&"beacon_001".into()).await.unwrap().unwrap().power, -70);
+        assert!(repo.find_by_id(&"beacon_002".into()).await.unwrap().is_none());
+        // 重启前创建的ID不能再次创建
+        assert!(repo.create(BeaconBuilder::new("beacon_100").build()).await.is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
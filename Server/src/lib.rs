@@ -0,0 +1,13 @@
+//! 蓝牙信标导航服务
+//!
+//! 服务端各层以库的形式提供，`main.rs` 只负责加载配置并启动服务器；
+//! 启用 `testkit` feature 后，集成测试可通过 [`testkit`] 构建数据并启动进程内服务器
+
+pub mod api;
+pub mod application;
+pub mod config;
+pub mod domain;
+pub mod error;
+pub mod infrastructure;
+#[cfg(any(test, feature = "testkit"))]
+pub mod testkit;
@@ -1,42 +1,46 @@
-mod api;
-mod domain;
-mod infrastructure;
-mod error;
-mod config;
-
-use axum::Router;
 use std::sync::Arc;
-use tower_http::cors::CorsLayer;
-use tracing_subscriber;
 
-use infrastructure::state::AppState;
-use api::routes;
+use blnav::api::routes;
+use blnav::config::AppConfig;
+use blnav::infrastructure;
+use blnav::infrastructure::state::AppState;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // 加载配置
+    let mut config = AppConfig::load()?;
+    config.apply_args(std::env::args().skip(1))?;
+    config.apply_override_file()?;
+
     // 初始化日志
     tracing_subscriber::fmt()
-        .with_max_level(tracing::Level::INFO)
+        .with_max_level(config.log_level.parse().unwrap_or(tracing::Level::INFO))
         .init();
 
     // 初始化应用状态
-    let state = Arc::new(AppState::new());
-    tracing::info!("Application state initialized");
+    let state = Arc::new(AppState::with_config(config));
+    let config = state.config();
+    let seeded = state.seed_data().await?;
+    if let Some(summary) = state.restore_journal().await? {
+        tracing::info!(
+            "Beacon data restored from journal ({} entries applied, {} beacons)",
+            summary.applied,
+            summary.beacon_count
+        );
+    }
+    tracing::info!("Application state initialized ({} beacons seeded)", seeded);
 
     // 构建路由
-    let app = Router::new()
-        .merge(routes::health::router())
-        .merge(routes::beacons::router())
-        .layer(CorsLayer::permissive())
-        .with_state(state);
+    let app = routes::app(Arc::clone(&state));
 
     // 启动服务器
-    let listener = tokio::net::TcpListener::bind("127.0.0.1:3000")
+    let listener = tokio::net::TcpListener::bind(config.addr())
         .await?;
     
-    tracing::info!("Server listening on http://127.0.0.1:3000");
+    tracing::info!("Server listening on http://{}", config.addr());
+    state.mark_ready("listener bound").await;
     
-    axum::serve(listener, app)
+    infrastructure::server::serve(listener, app, &config.server)
         .await?;
 
     Ok(())
@@ -0,0 +1,143 @@
+//! 测试数据构建器
+
+use crate::domain::{Beacon, Location};
+
+/// 测试用默认UUID
+pub const TEST_UUID: &str = "FDA50693-A4E2-4FB1-AFCF-C6EB07647825";
+
+/// Location 构建器
+pub struct LocationBuilder {
+    location: Location,
+}
+
+impl LocationBuilder {
+    /// 创建位于原点、1F、area_001 的位置
+    pub fn new() -> Self {
+        Self {
+            location: Location::new(0.0, 0.0, 0.0, "1F".to_string(), "area_001".to_string()),
+        }
+    }
+
+    /// 设置坐标
+    pub fn at(mut self, x: f64, y: f64, z: f64) -> Self {
+        self.location.x = x;
+        self.location.y = y;
+        self.location.z = z;
+        self
+    }
+
+    /// 设置楼层
+    pub fn floor(mut self, floor: &str) -> Self {
+        self.location.floor = floor.to_string();
+        self
+    }
+
+    /// 设置区域
+    pub fn area(mut self, area_id: &str) -> Self {
+        self.location.area_id = area_id.to_string();
+        self
+    }
+
+    pub fn build(self) -> Location {
+        self.location
+    }
+}
+
+impl Default for LocationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Beacon 构建器
+///
+/// 默认生成一个通过校验的活跃信标，只需覆盖测试关心的字段
+pub struct BeaconBuilder {
+    beacon: Beacon,
+}
+
+impl BeaconBuilder {
+    /// 以指定ID创建构建器
+    pub fn new(id: &str) -> Self {
+        Self {
+            beacon: Beacon::new(
+                id.into(),
+                TEST_UUID.to_string(),
+                10000,
+                1,
+                LocationBuilder::new().build(),
+                -59,
+                1000,
+                "active".to_string(),
+            ),
+        }
+    }
+
+    pub fn uuid(mut self, uuid: &str) -> Self {
+        self.beacon.uuid = uuid.to_string();
+        self
+    }
+
+    pub fn major(mut self, major: i32) -> Self {
+        self.beacon.major = major;
+        self
+    }
+
+    pub fn minor(mut self, minor: i32) -> Self {
+        self.beacon.minor = minor;
+        self
+    }
+
+    /// 设置坐标，楼层与区域保持不变
+    pub fn at(mut self, x: f64, y: f64, z: f64) -> Self {
+        self.beacon.location.x = x;
+        self.beacon.location.y = y;
+        self.beacon.location.z = z;
+        self
+    }
+
+    pub fn location(mut self, location: Location) -> Self {
+        self.beacon.location = location;
+        self
+    }
+
+    pub fn floor(mut self, floor: &str) -> Self {
+        self.beacon.location.floor = floor.to_string();
+        self
+    }
+
+    pub fn area(mut self, area_id: &str) -> Self {
+        self.beacon.location.area_id = area_id.to_string();
+        self
+    }
+
+    pub fn power(mut self, power: i32) -> Self {
+        self.beacon.power = power;
+        self
+    }
+
+    pub fn interval(mut self, interval: i32) -> Self {
+        self.beacon.interval = interval;
+        self
+    }
+
+    /// 设置所在灯具
+    pub fn fixture(mut self, fixture_id: &str) -> Self {
+        self.beacon.fixture_id = Some(fixture_id.to_string());
+        self
+    }
+
+    pub fn status(mut self, status: &str) -> Self {
+        self.beacon.status = status.to_string();
+        self
+    }
+
+    /// 标记为非活跃
+    pub fn inactive(self) -> Self {
+        self.status("inactive")
+    }
+
+    pub fn build(self) -> Beacon {
+        self.beacon
+    }
+}
@@ -0,0 +1,11 @@
+//! 测试工具模块
+//!
+//! 为集成测试提供数据构建器、进程内服务器和预置场景数据，
+//! 通过 `testkit` feature 启用
+
+pub mod builders;
+pub mod scenarios;
+pub mod server;
+
+pub use builders::{BeaconBuilder, LocationBuilder};
+pub use server::TestServer;
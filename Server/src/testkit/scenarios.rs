@@ -0,0 +1,60 @@
+//! 预置场景数据集
+
+use crate::domain::Beacon;
+use crate::testkit::BeaconBuilder;
+
+/// 单个 10m x 10m 房间，四角各一个信标
+pub fn square_room() -> Vec<Beacon> {
+    [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]
+        .iter()
+        .enumerate()
+        .map(|(i, &(x, y))| {
+            BeaconBuilder::new(&format!("room_{:03}", i + 1))
+                .minor(i as i32 + 1)
+                .at(x, y, 2.5)
+                .build()
+        })
+        .collect()
+}
+
+/// 同一楼层的两个区域，每个区域两个信标
+pub fn two_areas() -> Vec<Beacon> {
+    vec![
+        BeaconBuilder::new("area1_001").minor(1).at(0.0, 0.0, 2.5).area("area_001").build(),
+        BeaconBuilder::new("area1_002").minor(2).at(8.0, 0.0, 2.5).area("area_001").build(),
+        BeaconBuilder::new("area2_001").minor(3).at(0.0, 20.0, 2.5).area("area_002").build(),
+        BeaconBuilder::new("area2_002").minor(4).at(8.0, 20.0, 2.5).area("area_002").build(),
+    ]
+}
+
+/// 两个楼层各三个信标，其中 2F 有一个信标离线
+pub fn two_floors() -> Vec<Beacon> {
+    let mut beacons = Vec::new();
+    for (floor, base_minor) in [("1F", 100), ("2F", 200)] {
+        for (i, &(x, y)) in [(0.0, 0.0), (12.0, 0.0), (6.0, 10.0)].iter().enumerate() {
+            beacons.push(
+                BeaconBuilder::new(&format!("{}_{:03}", floor.to_lowercase(), i + 1))
+                    .minor(base_minor + i as i32)
+                    .at(x, y, 2.5)
+                    .floor(floor)
+                    .build(),
+            );
+        }
+    }
+    if let Some(beacon) = beacons.last_mut() {
+        beacon.status = "inactive".to_string();
+    }
+    beacons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scenarios_are_valid() {
+        for beacon in square_room().iter().chain(&two_areas()).chain(&two_floors()) {
+            assert!(beacon.validate().is_ok(), "invalid beacon {}", beacon.id);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! 进程内测试服务器
+
+use http_body_util::{BodyExt, Full};
+use hyper::body::Bytes;
+use hyper::header::{CONTENT_TYPE, HOST};
+use hyper::{HeaderMap, Method, Request, StatusCode};
+use hyper_util::rt::TokioIo;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+use crate::api::routes;
+use crate::config::ServerConfig;
+use crate::domain::Beacon;
+use crate::infrastructure::server::serve;
+use crate::infrastructure::AppState;
+
+/// 绑定在随机端口上的进程内服务器，析构时自动停止
+pub struct TestServer {
+    addr: SocketAddr,
+    state: Arc<AppState>,
+    handle: JoinHandle<std::io::Result<()>>,
+}
+
+impl TestServer {
+    /// 以默认状态和配置启动
+    pub async fn spawn() -> Self {
+        Self::spawn_with(AppState::new(), ServerConfig::default()).await
+    }
+
+    /// 以指定服务器配置启动
+    pub async fn spawn_with_config(config: ServerConfig) -> Self {
+        Self::spawn_with(AppState::new(), config).await
+    }
+
+    /// 启动前向仓储写入信标
+    pub async fn spawn_with_beacons(beacons: Vec<Beacon>) -> Self {
+        let state = AppState::new();
+        state
+            .beacon_repository()
+            .seed(beacons)
+            .await
+            .expect("invalid test beacon");
+        Self::spawn_with(state, ServerConfig::default()).await
+    }
+
+    /// 以指定状态和配置启动
+    pub async fn spawn_with(state: AppState, config: ServerConfig) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("failed to bind ephemeral port");
+        let addr = listener.local_addr().expect("listener has no local address");
+        let state = Arc::new(state);
+        let app = routes::app(Arc::clone(&state));
+        let handle = tokio::spawn(async move { serve(listener, app, &config).await });
+
+        Self { addr, state, handle }
+    }
+
+    /// 服务器监听地址
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// 拼接完整URL
+    pub fn url(&self, path: &str) -> String {
+        format!("http://{}{}", self.addr, path)
+    }
+
+    /// 服务器共享的应用状态
+    pub fn state(&self) -> Arc<AppState> {
+        Arc::clone(&self.state)
+    }
+
+    /// 发送 GET 请求
+    pub async fn get(&self, path: &str) -> (StatusCode, serde_json::Value) {
+        self.send(Method::GET, path, None).await
+    }
+
+    /// 发送 JSON 请求体的 POST 请求
+    pub async fn post_json(&self, path: &str, body: &serde_json::Value) -> (StatusCode, serde_json::Value) {
+        self.send(Method::POST, path, Some(body)).await
+    }
+
+    /// 在新的HTTP/1.1连接上发送请求，返回状态码与解析后的JSON响应体
+    ///
+    /// 响应体为空时返回 `Value::Null`
+    pub async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&serde_json::Value>,
+    ) -> (StatusCode, serde_json::Value) {
+        let mut builder = Request::builder().method(method).uri(path);
+        let body = match body {
+            Some(value) => {
+                builder = builder.header(CONTENT_TYPE, "application/json");
+                Bytes::from(value.to_string())
+            }
+            None => Bytes::new(),
+        };
+        let request = builder.body(Full::new(body)).expect("invalid request");
+
+        let (status, _, bytes) = self.request(request).await;
+        let value = if bytes.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_slice(&bytes).expect("response is not JSON")
+        };
+        (status, value)
+    }
+
+    /// 在新的HTTP/1.1连接上发送任意请求，返回原始响应
+    ///
+    /// 未设置 Host 头时自动补充
+    pub async fn request(&self, mut request: Request<Full<Bytes>>) -> (StatusCode, HeaderMap, Bytes) {
+        if !request.headers().contains_key(HOST) {
+            let host = self.addr.to_string().parse().expect("invalid host header");
+            request.headers_mut().insert(HOST, host);
+        }
+
+        let stream = TcpStream::connect(self.addr).await.expect("failed to connect");
+        let (mut sender, conn) = hyper::client::conn::http1::handshake(TokioIo::new(stream))
+            .await
+            .expect("handshake failed");
+        tokio::spawn(conn);
+
+        let response = sender.send_request(request).await.expect("request failed");
+        let (parts, body) = response.into_parts();
+        let bytes = body.collect().await.expect("failed to read body").to_bytes();
+        (parts.status, parts.headers, bytes)
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::scenarios;
+
+    #[tokio::test]
+    async fn test_spawn_and_get() {
+        let server = TestServer::spawn().await;
+        let (status, body) = server.get("/health").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "healthy");
+    }
+
+    #[tokio::test]
+    async fn test_spawn_with_beacons() {
+        let server = TestServer::spawn_with_beacons(scenarios::square_room()).await;
+        let count = server.state().beacon_repository().count().await.unwrap();
+        assert_eq!(count, scenarios::square_room().len());
+
+        let (status, body) = server.get("/api/all_beacons").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"].as_array().unwrap().len(), count);
+    }
+}
@@ -0,0 +1,20 @@
+//! 基于 testkit 的接口集成测试
+
+use blnav::testkit::{scenarios, BeaconBuilder, LocationBuilder, TestServer};
+use hyper::StatusCode;
+
+#[tokio::test]
+async fn test_list_beacons_by_floor() {
+    let mut beacons = scenarios::two_floors();
+    beacons.push(
+        BeaconBuilder::new("beacon_900")
+            .location(LocationBuilder::new().at(1.0, 2.0, 2.5).floor("3F").build())
+            .build(),
+    );
+    let server = TestServer::spawn_with_beacons(beacons).await;
+
+    let (status, body) = server.get("/api/all_beacons?floor=3F").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["page"]["total"], 1);
+    assert_eq!(body["data"][0]["id"], "beacon_900");
+}